@@ -2,10 +2,14 @@ use clap::Args;
 use clap::Subcommand;
 
 use super::device::DeviceArgs;
+use super::doctor::DoctorArgs;
 use super::emulator::EmulatorArgs;
 use super::flutter::FlutterArgs;
+use super::package::PackageArgs;
+use super::project::ProjectArgs;
 use super::psdk::PsdkArgs;
 use super::sdk::SdkArgs;
+use super::updates::UpdatesArgs;
 
 /// Классическая командная строка
 #[derive(Args)]
@@ -23,23 +27,35 @@ pub struct CliArgs {
 pub enum CliCommands {
     /// Работа с устройствами
     Device(DeviceArgs),
+    /// Проверить окружение и зависимости
+    Doctor(DoctorArgs),
     /// Работа с эмуляторами
     Emulator(EmulatorArgs),
     /// Работа с Flutter SDK
     Flutter(FlutterArgs),
+    /// Работа с пакетами RPM
+    Package(PackageArgs),
+    /// Работа с проектами
+    Project(ProjectArgs),
     /// Работа с Platform SDK
     Psdk(PsdkArgs),
     /// Работа с Аврора SDK
     Sdk(SdkArgs),
+    /// Проверить обновления SDK
+    Updates(UpdatesArgs),
 }
 
 /// Handling interface events
 pub fn run(arg: CliArgs) {
     match arg.command.unwrap() {
         CliCommands::Device(arg) => super::device::run(arg),
+        CliCommands::Doctor(arg) => super::doctor::run(arg),
         CliCommands::Emulator(arg) => super::emulator::run(arg),
         CliCommands::Flutter(arg) => super::flutter::run(arg),
+        CliCommands::Package(arg) => super::package::run(arg),
+        CliCommands::Project(arg) => super::project::run(arg),
         CliCommands::Psdk(arg) => super::psdk::run(arg),
         CliCommands::Sdk(arg) => super::sdk::run(arg),
+        CliCommands::Updates(arg) => super::updates::run(arg),
     }
 }
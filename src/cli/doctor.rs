@@ -0,0 +1,16 @@
+use clap::Args;
+
+use crate::feature::app_doctor::incoming::AppDoctorIncoming;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+
+#[derive(Args)]
+pub struct DoctorArgs {
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+pub fn run(_: DoctorArgs) {
+    AppDoctorIncoming::new().run(OutgoingType::Cli).print();
+}
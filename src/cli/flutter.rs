@@ -1,24 +1,51 @@
 use std::path::PathBuf;
 
+use clap::ArgGroup;
 use clap::Args;
+use clap::Subcommand;
 
 use crate::feature::flutter_available::incoming::FlutterAvailableIncoming;
 use crate::feature::flutter_download::incoming::FlutterDownloadIncoming;
 use crate::feature::flutter_info::incoming::FlutterInfoIncoming;
 use crate::feature::flutter_install::incoming::FlutterInstallIncoming;
 use crate::feature::flutter_project_format::incoming::FlutterProjectFormatIncoming;
+use crate::feature::flutter_project_mirror::incoming::FlutterProjectMirrorIncoming;
 use crate::feature::flutter_project_report::incoming::FlutterProjectReportIncoming;
+use crate::feature::flutter_project_run::incoming::FlutterBuildMode;
+use crate::feature::flutter_project_run::incoming::FlutterProjectRunIncoming;
 use crate::feature::flutter_terminal::incoming::FlutterTerminalIncoming;
 use crate::feature::flutter_uninstall::incoming::FlutterUninstallIncoming;
+use crate::feature::flutter_use::incoming::FlutterUseIncoming;
 use crate::feature::incoming::TraitIncoming;
 use crate::feature::outgoing::OutgoingType;
+use crate::service::pub_mirror::server::ServerPub;
+use crate::tools::constants;
+use crate::tools::format_utils::FormatMode;
+use crate::tools::gen_report::ReportFormat;
 use crate::tools::macros::print_error;
+use crate::tools::macros::print_info;
 use crate::tools::utils;
 
 #[derive(Args)]
 #[command(arg_required_else_help = true)]
-#[group(multiple = false)]
+#[command(group(ArgGroup::new("flutter").multiple(false).args([
+    "info",
+    "available",
+    "terminal",
+    "format",
+    "format_check",
+    "format_diff",
+    "report",
+    "download",
+    "install",
+    "uninstall",
+    "help",
+])))]
+#[group(skip)]
 pub struct FlutterArgs {
+    /// Subcommand
+    #[command(subcommand)]
+    command: Option<FlutterArgsGroup>,
     /// Информация по установленным Flutter SDK
     #[arg(long, default_value_t = false)]
     info: bool,
@@ -31,9 +58,18 @@ pub struct FlutterArgs {
     /// Форматировать проект Dart/C++
     #[arg(short, long, value_name = "path")]
     format: Option<PathBuf>,
+    /// Проверить форматирование проекта без изменений
+    #[arg(long, value_name = "path")]
+    format_check: Option<PathBuf>,
+    /// Показать diff форматирования проекта без изменений
+    #[arg(long, value_name = "path")]
+    format_diff: Option<PathBuf>,
     /// Сформировать отчет по плагинам проекта Flutter
     #[arg(short, long, value_name = "path")]
     report: Option<PathBuf>,
+    /// Формат отчета по плагинам проекта Flutter
+    #[arg(long, value_name = "format", requires = "report", default_value = "pdf", value_parser = ["pdf", "json", "md", "html", "dot", "mermaid"])]
+    report_format: String,
     /// Скачать Flutter SDK
     #[arg(short, long, default_value_t = false)]
     download: bool,
@@ -48,6 +84,65 @@ pub struct FlutterArgs {
     help: Option<bool>,
 }
 
+#[derive(Subcommand)]
+enum FlutterArgsGroup {
+    /// Собрать, установить и запустить проект на устройстве или эмуляторе
+    Run(FlutterRunArgs),
+    /// Выбрать версию Flutter SDK по умолчанию или закрепить ее в проекте
+    Use(FlutterUseArgs),
+    /// Зеркало пакетов pub для сборки без доступа к интернету
+    Mirror(FlutterMirrorArgs),
+}
+
+#[derive(Args)]
+pub struct FlutterRunArgs {
+    /// Директория проекта
+    #[arg(value_name = "path", default_value = ".")]
+    path: PathBuf,
+    /// Режим сборки
+    #[arg(short, long, value_name = "mode", default_value = "release", value_parser = ["debug", "profile", "release"])]
+    mode: String,
+    /// Запустить на эмуляторе
+    #[arg(short, long, default_value_t = false)]
+    emulator: bool,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct FlutterUseArgs {
+    /// Версия Flutter SDK, без версии устанавливается закрепленная в проекте
+    #[arg(value_name = "version")]
+    version: Option<String>,
+    /// Закрепить версию в проекте текущей директории
+    #[arg(short, long, default_value_t = false)]
+    pin: bool,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+#[derive(Args)]
+#[command(arg_required_else_help = true)]
+pub struct FlutterMirrorArgs {
+    /// Загрузить зависимости проекта в зеркало
+    #[arg(value_name = "path")]
+    path: Option<PathBuf>,
+    /// Директория зеркала
+    #[arg(short, long, value_name = "dir")]
+    output: Option<PathBuf>,
+    /// Запустить локальный сервер пакетов
+    #[arg(short, long, default_value_t = false)]
+    serve: bool,
+    /// Порт локального сервера пакетов
+    #[arg(short, long, value_name = "port", default_value_t = constants::PUB_MIRROR_PORT)]
+    port: u16,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
 pub fn run(arg: FlutterArgs) {
     if arg.available {
         FlutterAvailableIncoming::new().run(OutgoingType::Cli).print();
@@ -58,14 +153,29 @@ pub fn run(arg: FlutterArgs) {
         return;
     }
     if arg.terminal {
-        FlutterTerminalIncoming::new().run(OutgoingType::Cli).print();
+        match std::env::current_dir() {
+            Ok(path) => FlutterTerminalIncoming::new_path(path).run(OutgoingType::Cli).print(),
+            Err(_) => FlutterTerminalIncoming::new().run(OutgoingType::Cli).print(),
+        }
         return;
     }
-    if let Some(path) = arg.format {
+    let format = [
+        (arg.format, FormatMode::Write),
+        (arg.format_check, FormatMode::Check),
+        (arg.format_diff, FormatMode::Diff),
+    ]
+    .into_iter()
+    .find_map(|(path, mode)| path.map(|path| (path, mode)));
+    if let Some((path, mode)) = format {
         match utils::path_to_absolute(&path) {
             Some(path) => {
                 if path.is_dir() {
-                    FlutterProjectFormatIncoming::new(path).run(OutgoingType::Cli).print();
+                    let outgoing = FlutterProjectFormatIncoming::new_mode(path, mode).run(OutgoingType::Cli);
+                    outgoing.print();
+                    // Check and diff fail CI if need format
+                    if outgoing.is_failed() {
+                        std::process::exit(1);
+                    }
                 } else {
                     print_error!("укажите директорию проекта")
                 }
@@ -77,9 +187,13 @@ pub fn run(arg: FlutterArgs) {
     if let Some(path) = arg.report {
         match utils::path_to_absolute(&path) {
             Some(path) => {
-                FlutterProjectReportIncoming::new_path(path)
-                    .run(OutgoingType::Cli)
-                    .print();
+                let format = ReportFormat::from_name(&arg.report_format).unwrap_or_default();
+                let outgoing = FlutterProjectReportIncoming::new_path_format(path, format).run(OutgoingType::Cli);
+                outgoing.print();
+                // License violations fail CI
+                if outgoing.is_failed() {
+                    std::process::exit(1);
+                }
             }
             None => print_error!("проверьте путь к pubspec.yaml"),
         }
@@ -97,4 +211,49 @@ pub fn run(arg: FlutterArgs) {
         FlutterUninstallIncoming::new().run(OutgoingType::Cli).print();
         return;
     }
+    // Commands
+    if let Some(command) = arg.command {
+        match command {
+            FlutterArgsGroup::Run(arg) => match utils::path_to_absolute(&arg.path) {
+                Some(path) => {
+                    let mode = FlutterBuildMode::from_name(&arg.mode).unwrap_or_default();
+                    FlutterProjectRunIncoming::new(path, mode, arg.emulator)
+                        .run(OutgoingType::Cli)
+                        .print();
+                }
+                None => print_error!("проверьте путь к проекту"),
+            },
+            FlutterArgsGroup::Use(arg) => match (arg.version, std::env::current_dir()) {
+                (Some(version), Ok(path)) if arg.pin => {
+                    FlutterUseIncoming::new_pin(path, version)
+                        .run(OutgoingType::Cli)
+                        .print();
+                }
+                (Some(_), Err(_)) if arg.pin => print_error!("проверьте путь к проекту"),
+                (Some(version), _) => FlutterUseIncoming::new(version).run(OutgoingType::Cli).print(),
+                (None, Ok(path)) => FlutterUseIncoming::new_path(path).run(OutgoingType::Cli).print(),
+                (None, Err(_)) => print_error!("проверьте путь к проекту"),
+            },
+            FlutterArgsGroup::Mirror(arg) => {
+                let dir = match &arg.output {
+                    Some(dir) => PathBuf::from(dir),
+                    None => FlutterProjectMirrorIncoming::get_default_dir(),
+                };
+                if let Some(path) = arg.path {
+                    match utils::path_to_absolute(&path) {
+                        Some(path) => FlutterProjectMirrorIncoming::new_dir(path, dir.clone())
+                            .run(OutgoingType::Cli)
+                            .print(),
+                        None => print_error!("проверьте путь к проекту"),
+                    }
+                }
+                if arg.serve {
+                    match ServerPub::run(dir, arg.port) {
+                        Ok(_) => print_info!("сервер остановлен"),
+                        Err(error) => print_error!(error),
+                    }
+                }
+            }
+        }
+    }
 }
@@ -1,6 +1,10 @@
 pub mod args;
 pub mod device;
+pub mod doctor;
 pub mod emulator;
 pub mod flutter;
+pub mod package;
+pub mod project;
 pub mod psdk;
 pub mod sdk;
+pub mod updates;
@@ -0,0 +1,88 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use clap::Subcommand;
+
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::package_diff::incoming::PackageDiffIncoming;
+use crate::feature::package_inspect::incoming::PackageInspectIncoming;
+use crate::tools::macros::print_error;
+use crate::tools::utils;
+
+#[derive(Args)]
+#[command(arg_required_else_help = true)]
+pub struct PackageArgs {
+    /// Subcommand
+    #[command(subcommand)]
+    command: Option<PackageArgsGroup>,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+#[derive(Subcommand)]
+enum PackageArgsGroup {
+    /// Сравнить два пакета RPM
+    #[command(short_flag = 'd')]
+    Diff(PackageDiffArgs),
+    /// Информация о пакете RPM
+    #[command(short_flag = 'i')]
+    Inspect(PackageInspectArgs),
+}
+
+#[derive(Args)]
+#[command(arg_required_else_help = true)]
+pub struct PackageDiffArgs {
+    /// Предыдущий пакет RPM
+    #[arg(value_name = "old")]
+    old: PathBuf,
+    /// Новый пакет RPM
+    #[arg(value_name = "new")]
+    new: PathBuf,
+    /// Вывести результат в формате JSON
+    #[arg(short, long, default_value_t = false)]
+    json: bool,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+#[derive(Args)]
+#[command(arg_required_else_help = true)]
+pub struct PackageInspectArgs {
+    /// Пакет RPM
+    #[arg(value_name = "path")]
+    path: PathBuf,
+    /// Вывести результат в формате JSON
+    #[arg(short, long, default_value_t = false)]
+    json: bool,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+pub fn run(arg: PackageArgs) {
+    if let Some(command) = arg.command {
+        match command {
+            PackageArgsGroup::Diff(arg) => match (utils::path_to_absolute(&arg.old), utils::path_to_absolute(&arg.new))
+            {
+                (Some(old), Some(new)) => print(PackageDiffIncoming::new(old, new).run(OutgoingType::Cli), arg.json),
+                _ => print_error!("проверьте пути к пакетам"),
+            },
+            PackageArgsGroup::Inspect(arg) => match utils::path_to_absolute(&arg.path) {
+                Some(path) => print(PackageInspectIncoming::new(path).run(OutgoingType::Cli), arg.json),
+                None => print_error!("проверьте путь к пакету"),
+            },
+        }
+    }
+}
+
+fn print(outgoing: Box<dyn TraitOutgoing>, json: bool) {
+    if json {
+        println!("{}", outgoing.to_json());
+    } else {
+        outgoing.print();
+    }
+}
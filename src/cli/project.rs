@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use clap::Subcommand;
+
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::project_lint::incoming::ProjectLintIncoming;
+use crate::feature::project_new::incoming::ProjectNewIncoming;
+use crate::feature::project_validate::incoming::ProjectValidateIncoming;
+use crate::tools::macros::print_error;
+use crate::tools::utils;
+
+#[derive(Args)]
+#[command(arg_required_else_help = true)]
+pub struct ProjectArgs {
+    /// Subcommand
+    #[command(subcommand)]
+    command: Option<ProjectArgsGroup>,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+#[derive(Subcommand)]
+enum ProjectArgsGroup {
+    /// Проверить spec, desktop и иконки проекта
+    #[command(short_flag = 'l')]
+    Lint(ProjectLintArgs),
+    /// Создать проект из шаблона
+    #[command(short_flag = 'n')]
+    New(ProjectNewArgs),
+    /// Проверить desktop и иконки проекта или пакета RPM
+    #[command(short_flag = 'v')]
+    Validate(ProjectValidateArgs),
+}
+
+#[derive(Args)]
+#[command(arg_required_else_help = true)]
+pub struct ProjectNewArgs {
+    /// Название пакета: ru.<org>.<app>
+    #[arg(value_name = "name")]
+    name: String,
+    /// Шаблон проекта
+    #[arg(short, long, value_name = "template", default_value = "qml", value_parser = ["qml", "cpp", "flutter"])]
+    template: String,
+    /// Директория для создания проекта
+    #[arg(short, long, value_name = "path", default_value = ".")]
+    path: PathBuf,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct ProjectLintArgs {
+    /// Директория проекта
+    #[arg(value_name = "path", default_value = ".")]
+    path: PathBuf,
+    /// Вывести результат в формате JSON
+    #[arg(short, long, default_value_t = false)]
+    json: bool,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+#[derive(Args)]
+pub struct ProjectValidateArgs {
+    /// Директория проекта или пакет RPM
+    #[arg(value_name = "path", default_value = ".")]
+    path: PathBuf,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+pub fn run(arg: ProjectArgs) {
+    if let Some(command) = arg.command {
+        match command {
+            ProjectArgsGroup::Lint(arg) => match utils::path_to_absolute(&arg.path) {
+                Some(path) => {
+                    let outgoing = ProjectLintIncoming::new(path).run(OutgoingType::Cli);
+                    if arg.json {
+                        println!("{}", outgoing.to_json());
+                    } else {
+                        outgoing.print();
+                    }
+                    if outgoing.is_failed() {
+                        std::process::exit(1);
+                    }
+                }
+                None => print_error!("проверьте путь к проекту"),
+            },
+            ProjectArgsGroup::New(arg) => match utils::path_to_absolute(&arg.path) {
+                Some(path) => {
+                    ProjectNewIncoming::new(arg.template, arg.name, path)
+                        .run(OutgoingType::Cli)
+                        .print();
+                }
+                None => print_error!("проверьте путь к директории"),
+            },
+            ProjectArgsGroup::Validate(arg) => match utils::path_to_absolute(&arg.path) {
+                Some(path) => {
+                    let outgoing = ProjectValidateIncoming::new(path).run(OutgoingType::Cli);
+                    outgoing.print();
+                    if outgoing.is_failed() {
+                        std::process::exit(1);
+                    }
+                }
+                None => print_error!("проверьте путь к проекту или пакету"),
+            },
+        }
+    }
+}
@@ -10,10 +10,15 @@ use crate::feature::psdk_download::incoming::PsdkDownloadIncoming;
 use crate::feature::psdk_info::incoming::PsdkInfoIncoming;
 use crate::feature::psdk_install::incoming::PsdkInstallIncoming;
 use crate::feature::psdk_package_sign::incoming::PsdkPackageSignIncoming;
+use crate::feature::psdk_package_validate::incoming::PsdkPackageValidateIncoming;
+use crate::feature::psdk_project_ide::incoming::PsdkProjectIdeIncoming;
+use crate::feature::psdk_target_install::incoming::PsdkTargetInstallIncoming;
 use crate::feature::psdk_target_package_find::incoming::PsdkTargetPackageFindIncoming;
 use crate::feature::psdk_target_package_install::incoming::PsdkTargetPackageInstallIncoming;
 use crate::feature::psdk_target_package_uninstall::incoming::PsdkTargetPackageUninstallIncoming;
+use crate::feature::psdk_target_uninstall::incoming::PsdkTargetUninstallIncoming;
 use crate::feature::psdk_terminal::incoming::PsdkTerminalIncoming;
+use crate::feature::psdk_tooling_uninstall::incoming::PsdkToolingUninstallIncoming;
 use crate::feature::psdk_uninstall::incoming::PsdkUninstallIncoming;
 use crate::tools::macros::print_error;
 use crate::tools::utils;
@@ -59,6 +64,10 @@ enum PsdkArgsGroup {
     /// Работа с пакетами
     #[command(short_flag = 'p')]
     Package(PsdkPackageArgs),
+    /// Работа с Target и Tooling
+    Target(PsdkTargetArgs),
+    /// Работа с проектами
+    Project(PsdkProjectArgs),
 }
 
 #[derive(Args)]
@@ -68,6 +77,9 @@ pub struct PsdkPackageArgs {
     /// Подписать пакет открытым ключом
     #[arg(short, long, value_name = "path")]
     sign: Option<PathBuf>,
+    /// Проверить пакет валидатором RPM
+    #[arg(short, long, value_name = "path")]
+    validate: Option<PathBuf>,
     /// Поиск среди локальных пакетов
     #[arg(short, long, value_name = "package")]
     find: Option<String>,
@@ -82,6 +94,39 @@ pub struct PsdkPackageArgs {
     help: Option<bool>,
 }
 
+#[derive(Args)]
+#[group(multiple = false)]
+#[command(arg_required_else_help = true)]
+pub struct PsdkTargetArgs {
+    /// Установить Target из доступных Platform SDK
+    #[arg(short, long, default_value_t = false)]
+    install: bool,
+    /// Установить Target или Tooling из архива
+    #[arg(short = 'f', long, value_name = "path")]
+    install_file: Option<PathBuf>,
+    /// Удалить Target
+    #[arg(short, long, default_value_t = false)]
+    uninstall: bool,
+    /// Удалить Tooling вместе с его Target
+    #[arg(short = 't', long, default_value_t = false)]
+    uninstall_tooling: bool,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+#[derive(Args)]
+#[group(multiple = false)]
+#[command(arg_required_else_help = true)]
+pub struct PsdkProjectArgs {
+    /// Создать файлы IDE (CMake toolchain, compile_commands.json, clangd)
+    #[arg(short, long, value_name = "path")]
+    ide: Option<PathBuf>,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
 pub fn run(arg: PsdkArgs) {
     // Options
     if arg.info {
@@ -129,6 +174,15 @@ pub fn run(arg: PsdkArgs) {
                     }
                     return;
                 }
+                if let Some(path) = arg.validate {
+                    match utils::path_to_absolute(&path) {
+                        Some(path) => {
+                            PsdkPackageValidateIncoming::new(path).run(OutgoingType::Cli).print();
+                        }
+                        None => print_error!("проверьте путь к файлу"),
+                    }
+                    return;
+                }
                 if let Some(package) = arg.find {
                     PsdkTargetPackageFindIncoming::new(package)
                         .run(OutgoingType::Cli)
@@ -153,6 +207,44 @@ pub fn run(arg: PsdkArgs) {
                     return;
                 }
             }
+            PsdkArgsGroup::Target(arg) => {
+                if arg.install {
+                    PsdkTargetInstallIncoming::new().run(OutgoingType::Cli).print();
+                    return;
+                }
+                if let Some(path) = arg.install_file {
+                    match utils::path_to_absolute(&path) {
+                        Some(path) => {
+                            PsdkTargetInstallIncoming::new_path(path).run(OutgoingType::Cli).print();
+                        }
+                        None => print_error!("проверьте путь к файлу"),
+                    }
+                    return;
+                }
+                if arg.uninstall {
+                    PsdkTargetUninstallIncoming::new().run(OutgoingType::Cli).print();
+                    return;
+                }
+                if arg.uninstall_tooling {
+                    PsdkToolingUninstallIncoming::new().run(OutgoingType::Cli).print();
+                    return;
+                }
+            }
+            PsdkArgsGroup::Project(arg) => {
+                if let Some(path) = arg.ide {
+                    match utils::path_to_absolute(&path) {
+                        Some(path) => {
+                            if path.is_dir() {
+                                PsdkProjectIdeIncoming::new(path).run(OutgoingType::Cli).print();
+                            } else {
+                                print_error!("укажите директорию проекта")
+                            }
+                        }
+                        None => print_error!("проверьте путь к проекту"),
+                    }
+                    return;
+                }
+            }
         }
     }
 }
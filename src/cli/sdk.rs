@@ -1,19 +1,25 @@
 use std::path::PathBuf;
 
 use clap::Args;
+use clap::Subcommand;
 
 use crate::feature::incoming::TraitIncoming;
 use crate::feature::outgoing::OutgoingType;
 use crate::feature::sdk_available::incoming::SdkAvailableIncoming;
 use crate::feature::sdk_download::incoming::SdkDownloadIncoming;
+use crate::feature::sdk_engine_start::incoming::SdkEngineStartIncoming;
+use crate::feature::sdk_engine_status::incoming::SdkEngineStatusIncoming;
+use crate::feature::sdk_engine_stop::incoming::SdkEngineStopIncoming;
 use crate::feature::sdk_ide_close::incoming::SdkIdeCloseIncoming;
 use crate::feature::sdk_ide_open::incoming::SdkIdeOpenIncoming;
 use crate::feature::sdk_info::incoming::SdkInfoIncoming;
 use crate::feature::sdk_install::incoming::SdkInstallIncoming;
+use crate::feature::sdk_project_build::incoming::SdkProjectBuildIncoming;
 use crate::feature::sdk_project_format::incoming::SdkProjectFormatIncoming;
 use crate::feature::sdk_terminal::incoming::SdkTerminalIncoming;
 use crate::feature::sdk_tools::incoming::SdkToolsIncoming;
 use crate::feature::sdk_uninstall::incoming::SdkUninstallIncoming;
+use crate::tools::format_utils::FormatMode;
 use crate::tools::macros::print_error;
 use crate::tools::utils;
 
@@ -21,15 +27,18 @@ use crate::tools::utils;
 #[command(arg_required_else_help = true)]
 #[group(multiple = false)]
 pub struct SdkArgs {
+    /// Subcommand
+    #[command(subcommand)]
+    command: Option<SdkArgsGroup>,
     /// Информация по установленным Аврора SDK
     #[arg(long, default_value_t = false)]
     info: bool,
     /// Информация по доступным Аврора SDK
     #[arg(short, long, default_value_t = false)]
     available: bool,
-    /// Открыть IDE
-    #[arg(short, long, default_value_t = false)]
-    open: bool,
+    /// Открыть IDE, можно указать путь к проекту или файлу
+    #[arg(short, long, value_name = "path", num_args = 0..=1)]
+    open: Option<Option<PathBuf>>,
     /// Закрыть IDE
     #[arg(short, long, default_value_t = false)]
     close: bool,
@@ -39,9 +48,18 @@ pub struct SdkArgs {
     /// Открыть maintenance tools
     #[arg(short, long, default_value_t = false)]
     maintenance: bool,
+    /// Собрать проект в engine через MB2
+    #[arg(short, long, value_name = "path")]
+    build: Option<PathBuf>,
     /// Форматировать проект Qt/C++
     #[arg(short, long, value_name = "path")]
     format: Option<PathBuf>,
+    /// Проверить форматирование проекта без изменений
+    #[arg(long, value_name = "path")]
+    format_check: Option<PathBuf>,
+    /// Показать diff форматирования проекта без изменений
+    #[arg(long, value_name = "path")]
+    format_diff: Option<PathBuf>,
     /// Скачать Аврора SDK
     #[arg(short, long, default_value_t = false)]
     download: bool,
@@ -56,6 +74,40 @@ pub struct SdkArgs {
     help: Option<bool>,
 }
 
+#[derive(Subcommand)]
+enum SdkArgsGroup {
+    /// Работа с engine
+    #[command(short_flag = 'e')]
+    Engine(SdkEngineArgs),
+}
+
+#[derive(Args)]
+#[group(multiple = false)]
+#[command(arg_required_else_help = true)]
+pub struct SdkEngineArgs {
+    /// Запустить engine
+    #[arg(long, default_value_t = false)]
+    start: bool,
+    /// Остановить engine
+    #[arg(long, default_value_t = false)]
+    stop: bool,
+    /// Остановить engine после закрытия IDE
+    #[arg(long, default_value_t = false)]
+    stop_after_ide: bool,
+    /// Состояние engine
+    #[arg(long, default_value_t = false)]
+    status: bool,
+    /// Открыть терминал engine
+    #[arg(long, default_value_t = false)]
+    terminal: bool,
+    /// Ожидание закрытия IDE по ID
+    #[arg(long, value_name = "id", hide = true)]
+    watch_ide: Option<String>,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
 pub fn run(arg: SdkArgs) {
     if arg.info {
         SdkInfoIncoming::new().run(OutgoingType::Cli).print();
@@ -65,8 +117,14 @@ pub fn run(arg: SdkArgs) {
         SdkAvailableIncoming::new().run(OutgoingType::Cli).print();
         return;
     }
-    if arg.open {
-        SdkIdeOpenIncoming::new().run(OutgoingType::Cli).print();
+    if let Some(path) = arg.open {
+        match path {
+            Some(path) => match utils::path_to_absolute(&path) {
+                Some(path) => SdkIdeOpenIncoming::new_path(path).run(OutgoingType::Cli).print(),
+                None => print_error!("проверьте путь к проекту"),
+            },
+            None => SdkIdeOpenIncoming::new().run(OutgoingType::Cli).print(),
+        }
         return;
     }
     if arg.close {
@@ -81,11 +139,36 @@ pub fn run(arg: SdkArgs) {
         SdkToolsIncoming::new().run(OutgoingType::Cli).print();
         return;
     }
-    if let Some(path) = arg.format {
+    if let Some(path) = arg.build {
         match utils::path_to_absolute(&path) {
             Some(path) => {
                 if path.is_dir() {
-                    SdkProjectFormatIncoming::new(path).run(OutgoingType::Cli).print();
+                    SdkProjectBuildIncoming::new(path).run(OutgoingType::Cli).print();
+                } else {
+                    print_error!("укажите директорию проекта")
+                }
+            }
+            None => print_error!("проверьте путь к проекту"),
+        }
+        return;
+    }
+    let format = [
+        (arg.format, FormatMode::Write),
+        (arg.format_check, FormatMode::Check),
+        (arg.format_diff, FormatMode::Diff),
+    ]
+    .into_iter()
+    .find_map(|(path, mode)| path.map(|path| (path, mode)));
+    if let Some((path, mode)) = format {
+        match utils::path_to_absolute(&path) {
+            Some(path) => {
+                if path.is_dir() {
+                    let outgoing = SdkProjectFormatIncoming::new_mode(path, mode).run(OutgoingType::Cli);
+                    outgoing.print();
+                    // Check and diff fail CI if need format
+                    if outgoing.is_failed() {
+                        std::process::exit(1);
+                    }
                 } else {
                     print_error!("укажите директорию проекта")
                 }
@@ -106,4 +189,35 @@ pub fn run(arg: SdkArgs) {
         SdkUninstallIncoming::new().run(OutgoingType::Cli).print();
         return;
     }
+    // Commands
+    if let Some(command) = arg.command {
+        match command {
+            SdkArgsGroup::Engine(arg) => {
+                if arg.start {
+                    SdkEngineStartIncoming::new().run(OutgoingType::Cli).print();
+                    return;
+                }
+                if arg.stop {
+                    SdkEngineStopIncoming::new(false).run(OutgoingType::Cli).print();
+                    return;
+                }
+                if arg.stop_after_ide {
+                    SdkEngineStopIncoming::new(true).run(OutgoingType::Cli).print();
+                    return;
+                }
+                if arg.status {
+                    SdkEngineStatusIncoming::new().run(OutgoingType::Cli).print();
+                    return;
+                }
+                if arg.terminal {
+                    SdkTerminalIncoming::new().run(OutgoingType::Cli).print();
+                    return;
+                }
+                if let Some(id) = arg.watch_ide {
+                    SdkEngineStopIncoming::watch_ide(id);
+                    return;
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,40 @@
+use clap::Args;
+
+use crate::feature::app_updates::incoming::AppUpdatesIncoming;
+use crate::feature::flutter_install::incoming::FlutterInstallIncoming;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::psdk_install::incoming::PsdkInstallIncoming;
+use crate::feature::sdk_install::incoming::SdkInstallIncoming;
+use crate::models::app_update::model::AppUpdateModel;
+use crate::models::app_update::model::AppUpdateType;
+use crate::tools::macros::print_info;
+
+#[derive(Args)]
+#[group(multiple = false)]
+pub struct UpdatesArgs {
+    /// Установить все доступные обновления
+    #[arg(short, long, default_value_t = false)]
+    install: bool,
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
+}
+
+pub fn run(arg: UpdatesArgs) {
+    if arg.install {
+        let models = AppUpdateModel::search();
+        if models.is_empty() {
+            print_info!("обновления не найдены");
+        }
+        for model in models {
+            match model.update_type {
+                AppUpdateType::Sdk => SdkInstallIncoming::new_id(model.id).run(OutgoingType::Cli).print(),
+                AppUpdateType::Psdk => PsdkInstallIncoming::new_id(model.id).run(OutgoingType::Cli).print(),
+                AppUpdateType::Flutter => FlutterInstallIncoming::new_id(model.id).run(OutgoingType::Cli).print(),
+            }
+        }
+        return;
+    }
+    AppUpdatesIncoming::new().run(OutgoingType::Cli).print();
+}
@@ -0,0 +1,48 @@
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::doctor_check::model::DoctorCheckModel;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+
+use super::outgoing::AppDoctorOutgoing;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AppDoctorIncoming {}
+
+impl AppDoctorIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::AppDoctor)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new() -> Box<AppDoctorIncoming> {
+        Box::new(Self {})
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            (),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (): ()| async move {
+                let outgoing = Self::new().run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+}
+
+impl TraitIncoming for AppDoctorIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        StateMessageOutgoing::new_state(tr!("проверяем окружение...")).send(&send_type);
+        AppDoctorOutgoing::new(DoctorCheckModel::search())
+    }
+}
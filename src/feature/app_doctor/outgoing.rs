@@ -0,0 +1,50 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::models::TraitModel;
+use crate::models::doctor_check::model::DoctorCheckModel;
+use crate::models::doctor_check::model::DoctorCheckStatus;
+use crate::tools::macros::print_info;
+use crate::tools::macros::print_success;
+use crate::tools::macros::print_warning;
+use crate::tools::macros::tr;
+
+use super::incoming::AppDoctorIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AppDoctorOutgoing {
+    checks: Vec<DoctorCheckModel>,
+}
+
+impl AppDoctorOutgoing {
+    pub fn new(checks: Vec<DoctorCheckModel>) -> Box<AppDoctorOutgoing> {
+        Box::new(Self { checks })
+    }
+}
+
+impl TraitOutgoing for AppDoctorOutgoing {
+    fn print(&self) {
+        for item in &self.checks {
+            item.print();
+        }
+        let count = |status: DoctorCheckStatus| self.checks.iter().filter(|e| e.status == status).count();
+        let errors = count(DoctorCheckStatus::Error);
+        let warnings = count(DoctorCheckStatus::Warning);
+        if errors == 0 && warnings == 0 {
+            let message = tr!("окружение готово к работе");
+            print_success!(message);
+        } else if errors == 0 {
+            let message = tr!("предупреждений: {}", warnings);
+            print_info!(message);
+        } else {
+            let message = tr!("ошибок: {}, предупреждений: {}", errors, warnings);
+            print_warning!(message);
+        }
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(AppDoctorIncoming::name(), self.clone())
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::app_update::model::AppUpdateModel;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+
+use super::outgoing::AppUpdatesOutgoing;
+
+/// Interval check updates in D-Bus service
+const WATCH_INTERVAL: u64 = 6 * 60 * 60;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AppUpdatesIncoming {}
+
+impl AppUpdatesIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::AppUpdates)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new() -> Box<AppUpdatesIncoming> {
+        Box::new(Self {})
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            (),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (): ()| async move {
+                let outgoing = Self::new().run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    /// Periodic check updates, send to Listen if new update found
+    pub async fn watch() {
+        let mut ids: Vec<String> = vec![];
+        let mut interval = tokio::time::interval(Duration::from_secs(WATCH_INTERVAL));
+        loop {
+            interval.tick().await;
+            let models = tokio::task::block_in_place(AppUpdateModel::search);
+            if models.iter().any(|e| !ids.contains(&e.id)) {
+                AppUpdatesOutgoing::new(models.clone()).send(&OutgoingType::Dbus);
+            }
+            ids = models.iter().map(|e| e.id.clone()).collect();
+        }
+    }
+}
+
+impl TraitIncoming for AppUpdatesIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        StateMessageOutgoing::new_state(tr!("проверяем обновления...")).send(&send_type);
+        let models = AppUpdateModel::search();
+        if models.is_empty() {
+            StateMessageOutgoing::new_info(tr!("обновления не найдены"))
+        } else {
+            AppUpdatesOutgoing::new(models)
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::models::TraitModel;
+use crate::models::app_update::model::AppUpdateModel;
+use crate::tools::macros::print_info;
+use crate::tools::macros::tr;
+
+use super::incoming::AppUpdatesIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AppUpdatesOutgoing {
+    updates: Vec<AppUpdateModel>,
+}
+
+impl AppUpdatesOutgoing {
+    pub fn new(updates: Vec<AppUpdateModel>) -> Box<AppUpdatesOutgoing> {
+        Box::new(Self { updates })
+    }
+}
+
+impl TraitOutgoing for AppUpdatesOutgoing {
+    fn print(&self) {
+        for item in &self.updates {
+            item.print();
+        }
+        let message = tr!("установить все обновления: aurora-bot cli updates --install");
+        print_info!(message);
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(AppUpdatesIncoming::name(), self.clone())
+    }
+}
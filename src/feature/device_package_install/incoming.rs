@@ -124,7 +124,7 @@ impl DevicePackageInstallIncoming {
         );
     }
 
-    fn run_install_by_path(
+    pub fn run_install_by_path(
         model: &DeviceModel,
         send_type: &OutgoingType,
         path: &PathBuf,
@@ -124,7 +124,7 @@ impl EmulatorPackageInstallIncoming {
         );
     }
 
-    fn run_install_by_path(
+    pub fn run_install_by_path(
         model: &EmulatorModel,
         send_type: &OutgoingType,
         path: &PathBuf,
@@ -72,7 +72,7 @@ impl FlutterInstallIncoming {
         );
     }
 
-    fn run(
+    pub fn run_install(
         model: FlutterAvailableModel,
         send_type: &OutgoingType,
     ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
@@ -155,7 +155,7 @@ impl TraitIncoming for FlutterInstallIncoming {
         let models = FlutterAvailableModelSelect::search(&self.id, tr!("получаем список..."), &send_type);
         // Select
         match models.iter().count() {
-            1 => match Self::run(models.first().unwrap().clone(), &send_type) {
+            1 => match Self::run_install(models.first().unwrap().clone(), &send_type) {
                 Ok(result) => result,
                 Err(error) => StateMessageOutgoing::new_error(format!("{}", error)),
             },
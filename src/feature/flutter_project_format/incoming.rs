@@ -13,13 +13,21 @@ use crate::feature::state_message::outgoing::StateMessageOutgoing;
 use crate::models::flutter_installed::model::FlutterInstalledModel;
 use crate::service::dbus::server::IfaceData;
 use crate::tools::format_utils;
+use crate::tools::format_utils::FormatConfig;
+use crate::tools::format_utils::FormatMode;
+use crate::tools::format_utils::FormatResult;
 use crate::tools::macros::tr;
+use crate::tools::programs;
 use crate::tools::utils;
 
+use super::outgoing::FlutterProjectFormatOutgoing;
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct FlutterProjectFormatIncoming {
     id: Option<String>,
     path: PathBuf,
+    #[serde(default)]
+    mode: FormatMode,
 }
 
 impl FlutterProjectFormatIncoming {
@@ -30,11 +38,23 @@ impl FlutterProjectFormatIncoming {
     }
 
     pub fn new(path: PathBuf) -> Box<FlutterProjectFormatIncoming> {
-        Box::new(Self { id: None, path })
+        Self::new_mode(path, FormatMode::Write)
     }
 
     pub fn new_id(path: PathBuf, id: String) -> Box<FlutterProjectFormatIncoming> {
-        Box::new(Self { id: Some(id), path })
+        Self::new_mode_id(path, FormatMode::Write, id)
+    }
+
+    pub fn new_mode(path: PathBuf, mode: FormatMode) -> Box<FlutterProjectFormatIncoming> {
+        Box::new(Self { id: None, path, mode })
+    }
+
+    pub fn new_mode_id(path: PathBuf, mode: FormatMode, id: String) -> Box<FlutterProjectFormatIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            path,
+            mode,
+        })
     }
 
     fn select(&self, id: String) -> FlutterProjectFormatIncoming {
@@ -73,14 +93,79 @@ impl FlutterProjectFormatIncoming {
         );
     }
 
-    fn run(model: FlutterInstalledModel, path: &PathBuf) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+    pub fn dbus_method_run_mode(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "Mode"),
+            ("path", "mode"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, mode): (String, String)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    FormatMode::from_name(&mode),
+                ) {
+                    (Some(path), Some(mode)) => Self::new_mode(path, mode).run(OutgoingType::Dbus),
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                    (_, None) => StateMessageOutgoing::new_error(tr!("режим должен быть: write, check или diff")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_mode_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ModeById"),
+            ("path", "mode", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, mode, id): (String, String, String)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    FormatMode::from_name(&mode),
+                ) {
+                    (Some(path), Some(mode)) => Self::new_mode_id(path, mode, id).run(OutgoingType::Dbus),
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                    (_, None) => StateMessageOutgoing::new_error(tr!("режим должен быть: write, check или diff")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(
+        model: FlutterInstalledModel,
+        path: &PathBuf,
+        mode: &FormatMode,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let config = FormatConfig::load_flutter(path)?;
         // Format
-        let result_cpp = format_utils::cpp_format(path)?;
-        let result_dart = format_utils::dart_format(path, &PathBuf::from(model.dart))?;
+        let mut result = FormatResult::empty();
+        if config.cpp {
+            result.append(format_utils::cpp_format(path, mode, &config)?);
+        }
+        if config.dart {
+            result.append(format_utils::dart_format(
+                path,
+                &PathBuf::from(model.dart),
+                mode,
+                &config,
+            )?);
+        }
+        if config.cmake {
+            match programs::get_cmake_format() {
+                Ok(_) => result.append(format_utils::cmake_format(path, mode, &config)?),
+                Err(_) => StateMessageOutgoing::new_warning(tr!("не найден cmake-format, файлы CMake пропущены"))
+                    .send(send_type),
+            }
+        }
+        // Check without write
+        if *mode != FormatMode::Write {
+            return Ok(FlutterProjectFormatOutgoing::new(mode.clone(), result));
+        }
         // Count
-        let count_files = result_cpp.count_files + result_dart.count_files;
-        let count_formats = result_cpp.count_formats + result_dart.count_formats;
-        let count_exclude = result_cpp.count_exclude + result_dart.count_exclude;
+        let count_files = result.count_files;
+        let count_formats = result.count_formats;
+        let count_exclude = result.count_exclude;
         // Result
         if count_formats == 0 {
             Ok(StateMessageOutgoing::new_info(tr!("проект не требует форматирования")))
@@ -107,11 +192,15 @@ impl TraitIncoming for FlutterProjectFormatIncoming {
     fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
         // Search
         let key = FlutterProjectFormatIncoming::name();
-        let models =
-            FlutterInstalledModelSelect::search(&self.id, tr!("получаем информацию о Flutter SDK"), &send_type);
+        let models = FlutterInstalledModelSelect::search_project(
+            &self.id,
+            &self.path,
+            tr!("получаем информацию о Flutter SDK"),
+            &send_type,
+        );
         // Select
         match models.iter().count() {
-            1 => match Self::run(models.first().unwrap().clone(), &self.path) {
+            1 => match Self::run(models.first().unwrap().clone(), &self.path, &self.mode, &send_type) {
                 Ok(result) => result,
                 Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
             },
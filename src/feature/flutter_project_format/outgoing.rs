@@ -0,0 +1,48 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::tools::format_utils;
+use crate::tools::format_utils::FormatFile;
+use crate::tools::format_utils::FormatMode;
+use crate::tools::format_utils::FormatResult;
+
+use super::incoming::FlutterProjectFormatIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FlutterProjectFormatOutgoing {
+    mode: FormatMode,
+    passed: bool,
+    count_files: usize,
+    count_exclude: usize,
+    count_formats: usize,
+    files: Vec<FormatFile>,
+}
+
+impl FlutterProjectFormatOutgoing {
+    pub fn new(mode: FormatMode, result: FormatResult) -> Box<FlutterProjectFormatOutgoing> {
+        Box::new(Self {
+            mode,
+            passed: result.count_formats == 0,
+            count_files: result.count_files,
+            count_exclude: result.count_exclude,
+            count_formats: result.count_formats,
+            files: result.files,
+        })
+    }
+}
+
+impl TraitOutgoing for FlutterProjectFormatOutgoing {
+    fn print(&self) {
+        format_utils::print_files(&self.files, self.count_files, self.count_formats);
+    }
+
+    fn is_failed(&self) -> bool {
+        !self.passed
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(FlutterProjectFormatIncoming::name(), self.clone())
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::pubspec::model::PubspecModel;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::constants;
+use crate::tools::macros::tr;
+use crate::tools::single;
+use crate::tools::utils;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FlutterProjectMirrorIncoming {
+    path: PathBuf,
+    dir: Option<PathBuf>,
+}
+
+impl FlutterProjectMirrorIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::FlutterProjectMirror)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(path: PathBuf) -> Box<FlutterProjectMirrorIncoming> {
+        Box::new(Self { path, dir: None })
+    }
+
+    pub fn new_dir(path: PathBuf, dir: PathBuf) -> Box<FlutterProjectMirrorIncoming> {
+        Box::new(Self { path, dir: Some(dir) })
+    }
+
+    /// Default dir mirror in config dir application
+    pub fn get_default_dir() -> PathBuf {
+        utils::get_file_save_path(constants::PUB_MIRROR_DIR)
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_dir(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "Dir"),
+            ("path", "dir"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, dir): (String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_dir(path, PathBuf::from(dir)).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(
+        path: &PathBuf,
+        dir: &PathBuf,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let path = if path.is_dir() {
+            path.join("pubspec.yaml")
+        } else {
+            path.clone()
+        };
+        if !path.is_file() || path.file_name().unwrap().to_str().unwrap() != "pubspec.yaml" {
+            Err(tr!("укажите путь к проекту или pubspec.yaml"))?;
+        }
+        // Packages with versions: all hosted from lock or resolved graph by pub.dev
+        StateMessageOutgoing::new_state(tr!("получение зависимостей пакета")).send(send_type);
+        let packages = if !path.with_file_name("pubspec.lock").exists() {
+            StateMessageOutgoing::new_warning(tr!("не найден pubspec.lock, будут загружены последние версии пакетов"))
+                .send(send_type);
+            Self::resolve_dependencies(&path, send_type)?
+        } else {
+            let locks = match PubspecModel::parse_lock(&path) {
+                Ok(value) => value,
+                Err(_) => Err(tr!("не удалось прочитать pubspec.lock"))?,
+            };
+            let mut packages: Vec<(String, String)> = locks
+                .into_iter()
+                .filter(|(_, lock)| lock.source == "hosted")
+                .map(|(name, lock)| (name, lock.version))
+                .collect();
+            packages.sort();
+            packages
+        };
+        // Download hosted packages, git, path and sdk dependency not need in mirror
+        StateMessageOutgoing::new_state(tr!("загрузка пакетов в зеркало: {}", dir.to_string_lossy())).send(send_type);
+        let mut count_download = 0;
+        let mut count_exist = 0;
+        for (name, version) in &packages {
+            match single::get_request().download_dart_package(name, version, dir) {
+                Ok(true) => count_download += 1,
+                Ok(false) => count_exist += 1,
+                Err(error) => Err(tr!("не удалось загрузить {} {}: {}", name, version, error))?,
+            }
+        }
+        StateMessageOutgoing::new_info(tr!(
+            "запустите сервер: aurora-bot cli flutter mirror --serve --output {}",
+            dir.to_string_lossy()
+        ))
+        .send(send_type);
+        Ok(StateMessageOutgoing::new_success(tr!(
+            "загружено: {}, уже в зеркале: {}",
+            count_download,
+            count_exist
+        )))
+    }
+
+    /// Resolve hosted dependencies with dev_dependencies by pub.dev, latest versions
+    fn resolve_dependencies(
+        path: &PathBuf,
+        send_type: &OutgoingType,
+    ) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+        let names = match PubspecModel::parse_hosted_dependencies(path) {
+            Ok(value) => value,
+            Err(_) => Err(tr!("не удалось получить зависимости"))?,
+        };
+        let locks = HashMap::new();
+        let mut models: Vec<PubspecModel> = vec![];
+        let mut errors: Vec<String> = vec![];
+        for name in names {
+            if let Err(error) = single::get_request()._get_dart_packages(1, name.clone(), &locks, &mut models) {
+                StateMessageOutgoing::new_warning(tr!("не удалось получить {}: {}", name, error)).send(send_type);
+                errors.push(name);
+            }
+        }
+        if !errors.is_empty() {
+            Err(tr!("не удалось получить зависимости: {}", errors.join(", ")))?
+        }
+        Ok(models.into_iter().map(|e| (e.name, e.version)).collect())
+    }
+}
+
+impl TraitIncoming for FlutterProjectMirrorIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        let dir = self.dir.clone().unwrap_or_else(Self::get_default_dir);
+        match Self::run(&self.path, &dir, &send_type) {
+            Ok(result) => result,
+            Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::path::PathBuf;
 
 use dbus_crossroads::IfaceBuilder;
@@ -11,7 +12,9 @@ use crate::feature::outgoing::TraitOutgoing;
 use crate::feature::state_message::outgoing::StateMessageOutgoing;
 use crate::models::pubspec::model::PubspecModel;
 use crate::service::dbus::server::IfaceData;
-use crate::tools::gen_pdf;
+use crate::tools::gen_report;
+use crate::tools::gen_report::ReportFormat;
+use crate::tools::license_utils::LicenseConfig;
 use crate::tools::macros::tr;
 use crate::tools::single;
 use crate::tools::utils;
@@ -23,6 +26,8 @@ pub struct FlutterProjectReportIncoming {
     id: Option<String>,
     path: Option<PathBuf>,
     url: Option<String>,
+    #[serde(default)]
+    format: ReportFormat,
 }
 
 impl FlutterProjectReportIncoming {
@@ -37,6 +42,7 @@ impl FlutterProjectReportIncoming {
             id: None,
             path: Some(path),
             url: None,
+            format: ReportFormat::Pdf,
         })
     }
 
@@ -45,6 +51,7 @@ impl FlutterProjectReportIncoming {
             id: Some(id),
             path: Some(path),
             url: None,
+            format: ReportFormat::Pdf,
         })
     }
 
@@ -53,6 +60,7 @@ impl FlutterProjectReportIncoming {
             id: None,
             path: None,
             url: Some(url),
+            format: ReportFormat::Pdf,
         })
     }
 
@@ -61,6 +69,25 @@ impl FlutterProjectReportIncoming {
             id: Some(id),
             path: None,
             url: Some(url),
+            format: ReportFormat::Pdf,
+        })
+    }
+
+    pub fn new_path_format(path: PathBuf, format: ReportFormat) -> Box<FlutterProjectReportIncoming> {
+        Box::new(Self {
+            id: None,
+            path: Some(path),
+            url: None,
+            format,
+        })
+    }
+
+    pub fn new_url_format(url: String, format: ReportFormat) -> Box<FlutterProjectReportIncoming> {
+        Box::new(Self {
+            id: None,
+            path: None,
+            url: Some(url),
+            format,
         })
     }
 
@@ -118,8 +145,47 @@ impl FlutterProjectReportIncoming {
         );
     }
 
+    pub fn dbus_method_run_path_format(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "PathFormat"),
+            ("path", "format"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, format): (String, String)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    ReportFormat::from_name(&format),
+                ) {
+                    (Some(path), Some(format)) => Self::new_path_format(path, format).run(OutgoingType::Dbus),
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к pubspec.yaml")),
+                    (_, None) => {
+                        StateMessageOutgoing::new_error(tr!("формат должен быть: pdf, json, md, html, dot или mermaid"))
+                    }
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_url_format(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "UrlFormat"),
+            ("url", "format"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (url, format): (String, String)| async move {
+                let outgoing = match ReportFormat::from_name(&format) {
+                    Some(format) => Self::new_url_format(url, format).run(OutgoingType::Dbus),
+                    None => {
+                        StateMessageOutgoing::new_error(tr!("формат должен быть: pdf, json, md, html, dot или mermaid"))
+                    }
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
     fn run_path(
         path: &PathBuf,
+        format: &ReportFormat,
         send_type: &OutgoingType,
     ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
         if path.file_name().unwrap().to_str().unwrap() != "pubspec.yaml" {
@@ -133,15 +199,43 @@ impl FlutterProjectReportIncoming {
         };
         // Get all dependency
         StateMessageOutgoing::new_state(tr!("получение зависимостей пакета")).send(send_type);
-        let dependencies =
+        let mut dependencies =
             match PubspecModel::search_dependencies(path, StateMessageOutgoing::get_state_callback(send_type)) {
                 Ok(value) => value,
                 Err(_) => Err("не удалось получить зависимости")?,
             };
+        // Check plugins for Aurora OS
+        StateMessageOutgoing::new_state(tr!("проверка совместимости плагинов с ОС Аврора")).send(send_type);
+        let tags = utils::get_repo_flutter_plugins();
+        if tags.is_empty() {
+            StateMessageOutgoing::new_warning(tr!("не удалось получить список плагинов ОС Аврора")).send(send_type);
+        }
+        PubspecModel::check_aurora(&mut dependencies, &tags);
+        // Check licenses by allow-list
+        StateMessageOutgoing::new_state(tr!("проверка лицензий зависимостей")).send(send_type);
+        let config = LicenseConfig::load(path.parent().unwrap_or(Path::new(".")))?;
+        PubspecModel::check_licenses(&mut dependencies, &config);
+        let license_violations: Vec<String> = dependencies
+            .iter()
+            .filter(|e| e.is_license_violation())
+            .map(|e| {
+                format!(
+                    "{}: {} ({})",
+                    e.name,
+                    e.license.clone().unwrap_or("-".to_string()),
+                    e.license_status.as_ref().unwrap().to_name()
+                )
+            })
+            .collect();
+        let license_failed: Vec<String> = dependencies
+            .iter()
+            .filter(|e| e.is_license_failed())
+            .map(|e| e.name.clone())
+            .collect();
         // Gen report
         StateMessageOutgoing::new_state(tr!("генерация отчета")).send(send_type);
-        let path = utils::get_report_save_path();
-        match gen_pdf::gen_report_dart_packages(package, dependencies, &path) {
+        let path = utils::get_report_save_path(format.extension());
+        match gen_report::gen_report_dart_packages(format, package, dependencies, &path) {
             Ok(_) => {
                 let path = path.to_string_lossy().to_string();
                 Ok(FlutterProjectReportOutgoing::new(
@@ -151,13 +245,19 @@ impl FlutterProjectReportIncoming {
                     } else {
                         None
                     },
+                    license_violations,
+                    license_failed,
                 ))
             }
-            Err(_) => Err("не удалось создать отчет")?,
+            Err(error) => Err(tr!("не удалось создать отчет: {}", error))?,
         }
     }
 
-    fn run_url(url: &String, send_type: &OutgoingType) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+    fn run_url(
+        url: &String,
+        format: &ReportFormat,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
         StateMessageOutgoing::new_state(tr!("скачиваем файл...")).send(send_type);
         let url = match utils::get_https_url(url.to_string()) {
             Some(url) => url,
@@ -168,20 +268,20 @@ impl FlutterProjectReportIncoming {
             StateMessageOutgoing::get_state_callback_file_small(send_type),
         )?;
         // Run gen report
-        Self::run_path(&path, send_type)
+        Self::run_path(&path, format, send_type)
     }
 }
 
 impl TraitIncoming for FlutterProjectReportIncoming {
     fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
         if let Some(path) = &self.path {
-            return match Self::run_path(path, &send_type) {
+            return match Self::run_path(path, &self.format, &send_type) {
                 Ok(result) => result,
                 Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
             };
         }
         if let Some(url) = &self.url {
-            return match Self::run_url(url, &send_type) {
+            return match Self::run_url(url, &self.format, &send_type) {
                 Ok(result) => result,
                 Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
             };
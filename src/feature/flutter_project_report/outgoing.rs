@@ -4,7 +4,9 @@ use serde::Serialize;
 
 use crate::feature::outgoing::DataOutgoing;
 use crate::feature::outgoing::TraitOutgoing;
+use crate::tools::macros::print_error;
 use crate::tools::macros::print_success;
+use crate::tools::macros::print_warning;
 use crate::tools::macros::tr;
 
 use super::incoming::FlutterProjectReportIncoming;
@@ -13,11 +15,23 @@ use super::incoming::FlutterProjectReportIncoming;
 pub struct FlutterProjectReportOutgoing {
     path: String,
     base_64: Option<String>,
+    license_violations: Vec<String>,
+    license_failed: Vec<String>,
 }
 
 impl FlutterProjectReportOutgoing {
-    pub fn new(path: String, base_64: Option<String>) -> Box<FlutterProjectReportOutgoing> {
-        Box::new(Self { path, base_64 })
+    pub fn new(
+        path: String,
+        base_64: Option<String>,
+        license_violations: Vec<String>,
+        license_failed: Vec<String>,
+    ) -> Box<FlutterProjectReportOutgoing> {
+        Box::new(Self {
+            path,
+            base_64,
+            license_violations,
+            license_failed,
+        })
     }
 }
 
@@ -25,6 +39,21 @@ impl TraitOutgoing for FlutterProjectReportOutgoing {
     fn print(&self) {
         let out = tr!("отчет успешно сформирован: {}", self.path.blue());
         print_success!(out);
+        if !self.license_failed.is_empty() {
+            let message = tr!("не удалось получить лицензии: {}", self.license_failed.join(", "));
+            print_warning!(message);
+        }
+        if !self.license_violations.is_empty() {
+            for violation in &self.license_violations {
+                print_warning!(violation);
+            }
+            let message = tr!("нарушения лицензий: {}", self.license_violations.len());
+            print_error!(message);
+        }
+    }
+
+    fn is_failed(&self) -> bool {
+        !self.license_violations.is_empty()
     }
 
     fn to_json(&self) -> String {
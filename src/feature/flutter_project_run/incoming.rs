@@ -0,0 +1,499 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::device_package_install::incoming::DevicePackageInstallIncoming;
+use crate::feature::emulator_package_install::incoming::EmulatorPackageInstallIncoming;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_device::DeviceModelSelect;
+use crate::feature::selector::selects::select_emulator::EmulatorModelSelect;
+use crate::feature::selector::selects::select_flutter_installed::FlutterInstalledModelSelect;
+use crate::feature::selector::selects::select_psdk_target::PsdkTargetModelSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::TraitModel;
+use crate::models::device::model::DeviceModel;
+use crate::models::emulator::model::EmulatorModel;
+use crate::models::flutter_installed::model::FlutterInstalledModel;
+use crate::models::psdk_installed::model::PsdkInstalledModel;
+use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::models::session::model::SessionModel;
+use crate::service::command;
+use crate::service::command::exec;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+use crate::tools::utils;
+
+/// Build mode flutter-aurora: --debug, --profile, --release
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum FlutterBuildMode {
+    Debug,
+    Profile,
+    #[default]
+    Release,
+}
+
+impl FlutterBuildMode {
+    pub fn from_name(name: &str) -> Option<FlutterBuildMode> {
+        match name.to_lowercase().as_str() {
+            "debug" => Some(FlutterBuildMode::Debug),
+            "profile" => Some(FlutterBuildMode::Profile),
+            "release" => Some(FlutterBuildMode::Release),
+            _ => None,
+        }
+    }
+
+    pub fn to_name(&self) -> &str {
+        match self {
+            FlutterBuildMode::Debug => "debug",
+            FlutterBuildMode::Profile => "profile",
+            FlutterBuildMode::Release => "release",
+        }
+    }
+}
+
+/// Device or emulator for install and run application
+enum FlutterRunDestination {
+    Device(DeviceModel),
+    Emulator(EmulatorModel),
+}
+
+impl FlutterRunDestination {
+    fn arch(&self) -> String {
+        match self {
+            FlutterRunDestination::Device(model) => model.arch.clone(),
+            FlutterRunDestination::Emulator(model) => model.arch.clone(),
+        }
+    }
+
+    fn session_user(&self) -> Result<SessionModel, Box<dyn std::error::Error>> {
+        match self {
+            FlutterRunDestination::Device(model) => model.session_user(),
+            FlutterRunDestination::Emulator(model) => model.session_user(),
+        }
+    }
+
+    fn install(&self, send_type: &OutgoingType, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            FlutterRunDestination::Device(model) => {
+                DevicePackageInstallIncoming::run_install_by_path(model, send_type, path)?
+            }
+            FlutterRunDestination::Emulator(model) => {
+                EmulatorPackageInstallIncoming::run_install_by_path(model, send_type, path)?
+            }
+        };
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FlutterProjectRunIncoming {
+    id: Option<String>,
+    device_id: Option<String>,
+    target_id: Option<String>,
+    path: PathBuf,
+    #[serde(default)]
+    mode: FlutterBuildMode,
+    #[serde(default)]
+    is_emulator: bool,
+}
+
+impl FlutterProjectRunIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::FlutterProjectRun)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(path: PathBuf, mode: FlutterBuildMode, is_emulator: bool) -> Box<FlutterProjectRunIncoming> {
+        Box::new(Self {
+            id: None,
+            device_id: None,
+            target_id: None,
+            path,
+            mode,
+            is_emulator,
+        })
+    }
+
+    pub fn new_id(
+        path: PathBuf,
+        mode: FlutterBuildMode,
+        is_emulator: bool,
+        id: String,
+    ) -> Box<FlutterProjectRunIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            device_id: None,
+            target_id: None,
+            path,
+            mode,
+            is_emulator,
+        })
+    }
+
+    pub fn new_device_id(
+        path: PathBuf,
+        mode: FlutterBuildMode,
+        is_emulator: bool,
+        device_id: String,
+        id: String,
+    ) -> Box<FlutterProjectRunIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            device_id: Some(device_id),
+            target_id: None,
+            path,
+            mode,
+            is_emulator,
+        })
+    }
+
+    pub fn new_target_id(
+        path: PathBuf,
+        mode: FlutterBuildMode,
+        is_emulator: bool,
+        device_id: String,
+        target_id: String,
+        id: String,
+    ) -> Box<FlutterProjectRunIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            device_id: Some(device_id),
+            target_id: Some(target_id),
+            path,
+            mode,
+            is_emulator,
+        })
+    }
+
+    fn select(&self, id: String) -> FlutterProjectRunIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    fn select_with_device(&self, id: String, device_id: String) -> FlutterProjectRunIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.device_id = Some(device_id);
+        select
+    }
+
+    fn select_with_target(&self, id: String, device_id: String, target_id: String) -> FlutterProjectRunIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.device_id = Some(device_id);
+        select.target_id = Some(target_id);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("path", "mode", "is_emulator"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, mode, is_emulator): (String, String, bool)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    FlutterBuildMode::from_name(&mode),
+                ) {
+                    (Some(path), Some(mode)) => Self::new(path, mode, is_emulator).run(OutgoingType::Dbus),
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                    (_, None) => StateMessageOutgoing::new_error(tr!("режим должен быть: debug, profile или release")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("path", "mode", "is_emulator", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context,
+                  _,
+                  (path, mode, is_emulator, id): (String, String, bool, String)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    FlutterBuildMode::from_name(&mode),
+                ) {
+                    (Some(path), Some(mode)) => Self::new_id(path, mode, is_emulator, id).run(OutgoingType::Dbus),
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                    (_, None) => StateMessageOutgoing::new_error(tr!("режим должен быть: debug, profile или release")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_device_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "DeviceById"),
+            ("path", "mode", "is_emulator", "device_id", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context,
+                  _,
+                  (path, mode, is_emulator, device_id, id): (String, String, bool, String, String)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    FlutterBuildMode::from_name(&mode),
+                ) {
+                    (Some(path), Some(mode)) => {
+                        Self::new_device_id(path, mode, is_emulator, device_id, id).run(OutgoingType::Dbus)
+                    }
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                    (_, None) => StateMessageOutgoing::new_error(tr!("режим должен быть: debug, profile или release")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_target_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "TargetById"),
+            ("path", "mode", "is_emulator", "device_id", "target_id", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context,
+                  _,
+                  (path, mode, is_emulator, device_id, target_id, id): (
+                String,
+                String,
+                bool,
+                String,
+                String,
+                String,
+            )| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    FlutterBuildMode::from_name(&mode),
+                ) {
+                    (Some(path), Some(mode)) => {
+                        Self::new_target_id(path, mode, is_emulator, device_id, target_id, id).run(OutgoingType::Dbus)
+                    }
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                    (_, None) => StateMessageOutgoing::new_error(tr!("режим должен быть: debug, profile или release")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    /// Platform flutter-aurora by arch: aurora-arm, aurora-arm64, aurora-x64
+    fn get_target_platform(arch: &str) -> Result<&str, Box<dyn std::error::Error>> {
+        match arch {
+            "aarch64" => Ok("aurora-arm64"),
+            "armv7hl" => Ok("aurora-arm"),
+            "x86_64" => Ok("aurora-x64"),
+            _ => Err(tr!("архитектура {} не поддерживается Flutter", arch))?,
+        }
+    }
+
+    /// Latest RPM package after build: build/aurora/aurora-arm64/release/rpm/name.rpm
+    fn get_package(path: &Path, arch: &str) -> Option<PathBuf> {
+        WalkDir::new(path.join("build").join("aurora"))
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| {
+                let name = e.file_name().to_string_lossy();
+                name.ends_with(&format!("{arch}.rpm"))
+            })
+            .filter_map(|e| e.metadata().ok()?.modified().ok().map(|time| (time, e.into_path())))
+            .max_by_key(|(time, _)| *time)
+            .map(|(_, path)| path)
+    }
+
+    fn run(
+        flutter: FlutterInstalledModel,
+        destination: FlutterRunDestination,
+        target: PsdkTargetModel,
+        path: &Path,
+        mode: &FlutterBuildMode,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        if !path.join("pubspec.yaml").exists() {
+            Err(tr!("не найден файл pubspec.yaml"))?
+        }
+        let psdk = match PsdkInstalledModel::search_filter(|e| e.targets.contains(&target)).first() {
+            Some(value) => value.clone(),
+            None => Err(tr!("не удалось найти Platform SDK для {}", target.full_name))?,
+        };
+        let platform = Self::get_target_platform(&target.arch)?;
+        // Config Platform SDK
+        StateMessageOutgoing::new_state(tr!("настраиваем Platform SDK для Flutter")).send(send_type);
+        let output = exec::exec_wait_args(
+            &flutter.flutter,
+            ["config".to_string(), format!("--aurora-psdk-dir={}", psdk.dir)],
+        )?;
+        if !output.status.success() {
+            Err(tr!("не удалось настроить Platform SDK для Flutter"))?
+        }
+        // Build
+        StateMessageOutgoing::new_state(tr!("сборка проекта для {} ({})", target.full_name, mode.to_name()))
+            .send(send_type);
+        let output = exec::exec_wait_args_dir(
+            &flutter.flutter,
+            [
+                "build".to_string(),
+                "aurora".to_string(),
+                format!("--{}", mode.to_name()),
+                format!("--target-platform={platform}"),
+            ],
+            path,
+        )?;
+        if !output.status.success() {
+            let lines = utils::parse_output(output.stderr);
+            match lines.last() {
+                Some(line) => Err(tr!("сборка завершилась неудачей: {}", line))?,
+                None => Err(tr!("сборка завершилась неудачей"))?,
+            }
+        }
+        let package = match Self::get_package(path, &target.arch) {
+            Some(value) => value,
+            None => Err(tr!("пакет RPM не найден"))?,
+        };
+        // Sign
+        StateMessageOutgoing::new_state(tr!("подписываем пакет")).send(send_type);
+        let package = match command::psdk::rpm_sign(&psdk.chroot, &package) {
+            Some(value) => value,
+            None => Err(tr!("подпись пакета не удалось"))?,
+        };
+        let package_name = match utils::get_package_name(&package) {
+            Some(value) => value,
+            None => Err(tr!("не удалось получить название пакета"))?,
+        };
+        // Install
+        destination.install(send_type, &package)?;
+        // Run
+        StateMessageOutgoing::new_state(tr!("запускаем приложение")).send(send_type);
+        destination.session_user()?.run_package(package_name.clone())?;
+        Ok(StateMessageOutgoing::new_success(tr!(
+            "приложение {} запущено",
+            package_name
+        )))
+    }
+
+    /// Select Platform Target by arch device or emulator
+    fn run_target(
+        &self,
+        flutter: FlutterInstalledModel,
+        destination: FlutterRunDestination,
+        destination_id: String,
+        send_type: &OutgoingType,
+    ) -> Box<dyn TraitOutgoing> {
+        let key = FlutterProjectRunIncoming::name();
+        let arch = destination.arch();
+        let targets = PsdkInstalledModel::search()
+            .into_iter()
+            .flat_map(|e| e.targets)
+            .filter(|e| e.arch == arch)
+            .collect::<Vec<PsdkTargetModel>>();
+        let targets = PsdkTargetModelSelect::search(
+            &self.target_id,
+            tr!("получаем информацию о Platform Target"),
+            send_type,
+            targets,
+        );
+        match targets.iter().count() {
+            1 => match Self::run(
+                flutter,
+                destination,
+                targets.first().unwrap().clone(),
+                &self.path,
+                &self.mode,
+                send_type,
+            ) {
+                Ok(result) => result,
+                Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+            },
+            0 => StateMessageOutgoing::new_info(tr!("Platform Target для {} не найдены", arch)),
+            _ => match PsdkTargetModelSelect::select(key, send_type, targets, |id| {
+                self.select_with_target(flutter.get_id(), destination_id.clone(), id)
+            }) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform Target")),
+            },
+        }
+    }
+
+    /// Select running emulator
+    fn run_emulator(&self, flutter: FlutterInstalledModel, send_type: &OutgoingType) -> Box<dyn TraitOutgoing> {
+        let key = FlutterProjectRunIncoming::name();
+        let models =
+            EmulatorModelSelect::search(&self.device_id, send_type, tr!("ищем запущенный эмулятор"), Some(true));
+        match models.iter().count() {
+            1 => {
+                let emulator = models.first().unwrap().clone();
+                if !emulator.is_running {
+                    return StateMessageOutgoing::new_info(tr!("эмулятор должен быть запущен"));
+                }
+                let id = emulator.get_id();
+                self.run_target(flutter, FlutterRunDestination::Emulator(emulator), id, send_type)
+            }
+            0 => StateMessageOutgoing::new_info(tr!("запущенные эмуляторы не найдены")),
+            _ => match EmulatorModelSelect::select(key, send_type, models, |id| {
+                self.select_with_device(flutter.get_id(), id)
+            }) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить эмулятор")),
+            },
+        }
+    }
+
+    /// Select device
+    fn run_device(&self, flutter: FlutterInstalledModel, send_type: &OutgoingType) -> Box<dyn TraitOutgoing> {
+        let key = FlutterProjectRunIncoming::name();
+        let models = DeviceModelSelect::search(&self.device_id, tr!("получаем список устройств"), send_type);
+        match models.iter().count() {
+            1 => {
+                let device = models.first().unwrap().clone();
+                let id = device.get_id();
+                self.run_target(flutter, FlutterRunDestination::Device(device), id, send_type)
+            }
+            0 => StateMessageOutgoing::new_info(tr!("устройства не найдены")),
+            _ => match DeviceModelSelect::select(key, send_type, models, |id| {
+                self.select_with_device(flutter.get_id(), id)
+            }) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить устройство")),
+            },
+        }
+    }
+}
+
+impl TraitIncoming for FlutterProjectRunIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Search
+        let key = FlutterProjectRunIncoming::name();
+        let models = FlutterInstalledModelSelect::search_project(
+            &self.id,
+            &self.path,
+            tr!("получаем информацию о Flutter SDK"),
+            &send_type,
+        );
+        // Select
+        match models.iter().count() {
+            1 => {
+                let flutter = models.first().unwrap().clone();
+                if self.is_emulator {
+                    self.run_emulator(flutter, &send_type)
+                } else {
+                    self.run_device(flutter, &send_type)
+                }
+            }
+            0 => StateMessageOutgoing::new_info(tr!("Flutter SDK не найдены")),
+            _ => match FlutterInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Flutter SDK")),
+            },
+        }
+    }
+}
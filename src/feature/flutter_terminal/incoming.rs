@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use dbus_crossroads::IfaceBuilder;
 use maplit::hashmap;
 use serde::Deserialize;
@@ -13,10 +15,13 @@ use crate::models::flutter_installed::model::FlutterInstalledModel;
 use crate::service::dbus::server::IfaceData;
 use crate::tools::macros::tr;
 use crate::tools::terminal;
+use crate::tools::utils;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct FlutterTerminalIncoming {
     id: Option<String>,
+    #[serde(default)]
+    path: Option<PathBuf>,
 }
 
 impl FlutterTerminalIncoming {
@@ -27,11 +32,21 @@ impl FlutterTerminalIncoming {
     }
 
     pub fn new() -> Box<FlutterTerminalIncoming> {
-        Box::new(Self { id: None })
+        Box::new(Self { id: None, path: None })
     }
 
     pub fn new_id(id: String) -> Box<FlutterTerminalIncoming> {
-        Box::new(Self { id: Some(id) })
+        Box::new(Self {
+            id: Some(id),
+            path: None,
+        })
+    }
+
+    pub fn new_path(path: PathBuf) -> Box<FlutterTerminalIncoming> {
+        Box::new(Self {
+            id: None,
+            path: Some(path),
+        })
     }
 
     fn select(&self, id: String) -> FlutterTerminalIncoming {
@@ -64,6 +79,21 @@ impl FlutterTerminalIncoming {
         );
     }
 
+    pub fn dbus_method_run_path(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "Path"),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_path(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
     fn run(model: FlutterInstalledModel) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
         let command = terminal::command_aliases(hashmap! {
             "flutter" => model.flutter,
@@ -77,8 +107,11 @@ impl TraitIncoming for FlutterTerminalIncoming {
     fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
         // Search
         let key = FlutterTerminalIncoming::name();
-        let models =
-            FlutterInstalledModelSelect::search(&self.id, tr!("ищем Flutter SDK для открытия терминала"), &send_type);
+        let text = tr!("ищем Flutter SDK для открытия терминала");
+        let models = match &self.path {
+            Some(path) => FlutterInstalledModelSelect::search_project(&self.id, path, text, &send_type),
+            None => FlutterInstalledModelSelect::search_default(&self.id, text, &send_type),
+        };
         // Select
         match models.iter().count() {
             1 => match Self::run(models.first().unwrap().clone()) {
@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::flutter_install::incoming::FlutterInstallIncoming;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::configuration::Config;
+use crate::models::flutter_available::model::FlutterAvailableModel;
+use crate::models::flutter_installed::model::FlutterInstalledModel;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::constants;
+use crate::tools::macros::tr;
+use crate::tools::utils;
+
+/// Version - global default, version with path - pin to project, path - install pin project
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FlutterUseIncoming {
+    version: Option<String>,
+    path: Option<PathBuf>,
+}
+
+impl FlutterUseIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::FlutterUse)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(version: String) -> Box<FlutterUseIncoming> {
+        Box::new(Self {
+            version: Some(version),
+            path: None,
+        })
+    }
+
+    pub fn new_path(path: PathBuf) -> Box<FlutterUseIncoming> {
+        Box::new(Self {
+            version: None,
+            path: Some(path),
+        })
+    }
+
+    pub fn new_pin(path: PathBuf, version: String) -> Box<FlutterUseIncoming> {
+        Box::new(Self {
+            version: Some(version),
+            path: Some(path),
+        })
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("version",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (version,): (String,)| async move {
+                let outgoing = Self::new(version).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_path(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "Path"),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_path(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_pin(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "Pin"),
+            ("path", "version"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, version): (String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_pin(path, version).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    /// Install Flutter SDK by version if not found
+    fn install(
+        version: &str,
+        send_type: &OutgoingType,
+    ) -> Result<Option<Box<dyn TraitOutgoing>>, Box<dyn std::error::Error>> {
+        if !FlutterInstalledModel::search_filter(|e| e.is_version(version)).is_empty() {
+            return Ok(None);
+        }
+        StateMessageOutgoing::new_state(tr!("Flutter SDK {} не установлен, получаем список...", version))
+            .send(send_type);
+        let available = FlutterAvailableModel::search_filter(|e| {
+            FlutterInstalledModel::compare_version(&e.version, version) == Ordering::Equal
+        });
+        let Some(model) = available.first() else {
+            Err(tr!("Flutter SDK {} не найден среди доступных", version))?
+        };
+        let outgoing = FlutterInstallIncoming::run_install(model.clone(), send_type)?;
+        if FlutterInstalledModel::search_filter(|e| e.is_version(version)).is_empty() {
+            return Ok(Some(outgoing));
+        }
+        outgoing.send(send_type);
+        Ok(None)
+    }
+
+    fn run(
+        version: Option<String>,
+        path: Option<PathBuf>,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        match (version, path) {
+            // Pin version to project
+            (Some(version), Some(path)) => {
+                let version = FlutterInstalledModel::clear_version(&version);
+                if let Some(outgoing) = Self::install(&version, send_type)? {
+                    return Ok(outgoing);
+                }
+                let file = FlutterInstalledModel::write_pin(&path, &version)?;
+                Ok(StateMessageOutgoing::new_success(tr!(
+                    "Flutter SDK {} закреплен в {}",
+                    version,
+                    file.to_string_lossy()
+                )))
+            }
+            // Install pin version project
+            (None, Some(path)) => {
+                let Some((file, version)) = FlutterInstalledModel::read_pin(&path) else {
+                    Err(tr!(
+                        "не найден файл {}, укажите версию Flutter SDK",
+                        constants::FLUTTER_VERSION_FILE
+                    ))?
+                };
+                if let Some(outgoing) = Self::install(&version, send_type)? {
+                    return Ok(outgoing);
+                }
+                Ok(StateMessageOutgoing::new_success(tr!(
+                    "Flutter SDK {} из {} установлен",
+                    version,
+                    file.to_string_lossy()
+                )))
+            }
+            // Global default version
+            (Some(version), None) => {
+                let version = FlutterInstalledModel::clear_version(&version);
+                if let Some(outgoing) = Self::install(&version, send_type)? {
+                    return Ok(outgoing);
+                }
+                if Config::save_flutter_default(Some(version.clone())) {
+                    Ok(StateMessageOutgoing::new_success(tr!(
+                        "Flutter SDK {} используется по умолчанию",
+                        version
+                    )))
+                } else {
+                    Ok(StateMessageOutgoing::new_info(tr!(
+                        "Flutter SDK {} уже используется по умолчанию",
+                        version
+                    )))
+                }
+            }
+            (None, None) => Err(tr!("укажите версию Flutter SDK"))?,
+        }
+    }
+}
+
+impl TraitIncoming for FlutterUseIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        match Self::run(self.version.clone(), self.path.clone(), &send_type) {
+            Ok(result) => result,
+            Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+        }
+    }
+}
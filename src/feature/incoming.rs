@@ -1,7 +1,9 @@
 use serde::Deserialize;
 
+use crate::feature::app_doctor::incoming::AppDoctorIncoming;
 use crate::feature::app_open_dir::incoming::AppOpenDirIncoming;
 use crate::feature::app_open_file::incoming::AppOpenFileIncoming;
+use crate::feature::app_updates::incoming::AppUpdatesIncoming;
 use crate::feature::demo_app_info::incoming::DemoAppInfoIncoming;
 use crate::feature::device_info::incoming::DeviceInfoIncoming;
 use crate::feature::device_package_install::incoming::DevicePackageInstallIncoming;
@@ -13,17 +15,34 @@ use crate::feature::device_terminal::incoming::DeviceTerminalIncoming;
 use crate::feature::device_upload::incoming::DeviceUploadIncoming;
 use crate::feature::flutter_install::incoming::FlutterInstallIncoming;
 use crate::feature::flutter_project_format::incoming::FlutterProjectFormatIncoming;
+use crate::feature::flutter_project_mirror::incoming::FlutterProjectMirrorIncoming;
 use crate::feature::flutter_project_report::incoming::FlutterProjectReportIncoming;
+use crate::feature::flutter_project_run::incoming::FlutterProjectRunIncoming;
 use crate::feature::flutter_uninstall::incoming::FlutterUninstallIncoming;
+use crate::feature::flutter_use::incoming::FlutterUseIncoming;
+use crate::feature::package_diff::incoming::PackageDiffIncoming;
+use crate::feature::package_inspect::incoming::PackageInspectIncoming;
+use crate::feature::project_lint::incoming::ProjectLintIncoming;
+use crate::feature::project_new::incoming::ProjectNewIncoming;
+use crate::feature::project_validate::incoming::ProjectValidateIncoming;
 use crate::feature::psdk_install::incoming::PsdkInstallIncoming;
 use crate::feature::psdk_package_sign::incoming::PsdkPackageSignIncoming;
+use crate::feature::psdk_package_validate::incoming::PsdkPackageValidateIncoming;
+use crate::feature::psdk_project_ide::incoming::PsdkProjectIdeIncoming;
+use crate::feature::psdk_target_install::incoming::PsdkTargetInstallIncoming;
 use crate::feature::psdk_target_package_find::incoming::PsdkTargetPackageFindIncoming;
 use crate::feature::psdk_target_package_install::incoming::PsdkTargetPackageInstallIncoming;
 use crate::feature::psdk_target_package_uninstall::incoming::PsdkTargetPackageUninstallIncoming;
+use crate::feature::psdk_target_uninstall::incoming::PsdkTargetUninstallIncoming;
+use crate::feature::psdk_tooling_uninstall::incoming::PsdkToolingUninstallIncoming;
 use crate::feature::psdk_uninstall::incoming::PsdkUninstallIncoming;
+use crate::feature::sdk_engine_start::incoming::SdkEngineStartIncoming;
+use crate::feature::sdk_engine_status::incoming::SdkEngineStatusIncoming;
+use crate::feature::sdk_engine_stop::incoming::SdkEngineStopIncoming;
 use crate::feature::sdk_ide_close::incoming::SdkIdeCloseIncoming;
 use crate::feature::sdk_ide_open::incoming::SdkIdeOpenIncoming;
 use crate::feature::sdk_install::incoming::SdkInstallIncoming;
+use crate::feature::sdk_project_build::incoming::SdkProjectBuildIncoming;
 use crate::feature::sdk_project_format::incoming::SdkProjectFormatIncoming;
 use crate::feature::sdk_terminal::incoming::SdkTerminalIncoming;
 use crate::feature::sdk_uninstall::incoming::SdkUninstallIncoming;
@@ -100,6 +119,11 @@ impl ClientMethodsKey {
                 let model = serde_json::from_str::<AppAuthLogoutIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::AppDoctor => {
+                print_debug!("> AppDoctor: {}", value);
+                let model = serde_json::from_str::<AppDoctorIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::AppInfo => {
                 print_debug!("> AppInfo: {}", value);
                 let model = serde_json::from_str::<AppInfoIncoming>(&value)?;
@@ -115,6 +139,11 @@ impl ClientMethodsKey {
                 let model = serde_json::from_str::<AppOpenFileIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::AppUpdates => {
+                print_debug!("> AppUpdates: {}", value);
+                let model = serde_json::from_str::<AppUpdatesIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::DemoAppInfo => {
                 print_debug!("> DemoAppInfo: {}", value);
                 let model = serde_json::from_str::<DemoAppInfoIncoming>(&value)?;
@@ -245,11 +274,21 @@ impl ClientMethodsKey {
                 let model = serde_json::from_str::<FlutterProjectFormatIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::FlutterProjectMirror => {
+                print_debug!("> FlutterProjectMirror: {}", value);
+                let model = serde_json::from_str::<FlutterProjectMirrorIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::FlutterProjectReport => {
                 print_debug!("> FlutterProjectReport: {}", value);
                 let model = serde_json::from_str::<FlutterProjectReportIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::FlutterProjectRun => {
+                print_debug!("> FlutterProjectRun: {}", value);
+                let model = serde_json::from_str::<FlutterProjectRunIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::FlutterSync => {
                 print_debug!("> FlutterSync: {}", value);
                 let model = serde_json::from_str::<FlutterSyncIncoming>(&value)?;
@@ -265,6 +304,36 @@ impl ClientMethodsKey {
                 let model = serde_json::from_str::<FlutterUninstallIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::FlutterUse => {
+                print_debug!("> FlutterUse: {}", value);
+                let model = serde_json::from_str::<FlutterUseIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
+            ClientMethodsKey::PackageDiff => {
+                print_debug!("> PackageDiff: {}", value);
+                let model = serde_json::from_str::<PackageDiffIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
+            ClientMethodsKey::PackageInspect => {
+                print_debug!("> PackageInspect: {}", value);
+                let model = serde_json::from_str::<PackageInspectIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
+            ClientMethodsKey::ProjectLint => {
+                print_debug!("> ProjectLint: {}", value);
+                let model = serde_json::from_str::<ProjectLintIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
+            ClientMethodsKey::ProjectNew => {
+                print_debug!("> ProjectNew: {}", value);
+                let model = serde_json::from_str::<ProjectNewIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
+            ClientMethodsKey::ProjectValidate => {
+                print_debug!("> ProjectValidate: {}", value);
+                let model = serde_json::from_str::<ProjectValidateIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::PsdkAvailable => {
                 print_debug!("> PsdkAvailable: {}", value);
                 let model = serde_json::from_str::<PsdkAvailableIncoming>(&value)?;
@@ -290,11 +359,26 @@ impl ClientMethodsKey {
                 let model = serde_json::from_str::<PsdkPackageSignIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::PsdkPackageValidate => {
+                print_debug!("> PsdkPackageValidate: {}", value);
+                let model = serde_json::from_str::<PsdkPackageValidateIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
+            ClientMethodsKey::PsdkProjectIde => {
+                print_debug!("> PsdkProjectIde: {}", value);
+                let model = serde_json::from_str::<PsdkProjectIdeIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::PsdkSync => {
                 print_debug!("> PsdkSync: {}", value);
                 let model = serde_json::from_str::<PsdkSyncIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::PsdkTargetInstall => {
+                print_debug!("> PsdkTargetInstall: {}", value);
+                let model = serde_json::from_str::<PsdkTargetInstallIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::PsdkTargetPackageInstall => {
                 print_debug!("> PsdkTargetPackageInstall: {}", value);
                 let model = serde_json::from_str::<PsdkTargetPackageInstallIncoming>(&value)?;
@@ -310,11 +394,21 @@ impl ClientMethodsKey {
                 let model = serde_json::from_str::<PsdkTargetPackageUninstallIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::PsdkTargetUninstall => {
+                print_debug!("> PsdkTargetUninstall: {}", value);
+                let model = serde_json::from_str::<PsdkTargetUninstallIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::PsdkTerminal => {
                 print_debug!("> PsdkTerminal: {}", value);
                 let model = serde_json::from_str::<PsdkTerminalIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::PsdkToolingUninstall => {
+                print_debug!("> PsdkToolingUninstall: {}", value);
+                let model = serde_json::from_str::<PsdkToolingUninstallIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::PsdkUninstall => {
                 print_debug!("> PsdkUninstall: {}", value);
                 let model = serde_json::from_str::<PsdkUninstallIncoming>(&value)?;
@@ -330,6 +424,21 @@ impl ClientMethodsKey {
                 let model = serde_json::from_str::<SdkDownloadIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::SdkEngineStart => {
+                print_debug!("> SdkEngineStart: {}", value);
+                let model = serde_json::from_str::<SdkEngineStartIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
+            ClientMethodsKey::SdkEngineStatus => {
+                print_debug!("> SdkEngineStatus: {}", value);
+                let model = serde_json::from_str::<SdkEngineStatusIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
+            ClientMethodsKey::SdkEngineStop => {
+                print_debug!("> SdkEngineStop: {}", value);
+                let model = serde_json::from_str::<SdkEngineStopIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::SdkIdeClose => {
                 print_debug!("> SdkIdeClose: {}", value);
                 let model = serde_json::from_str::<SdkIdeCloseIncoming>(&value)?;
@@ -350,6 +459,11 @@ impl ClientMethodsKey {
                 let model = serde_json::from_str::<SdkInstallIncoming>(&value)?;
                 Ok(Box::new(model))
             }
+            ClientMethodsKey::SdkProjectBuild => {
+                print_debug!("> SdkProjectBuild: {}", value);
+                let model = serde_json::from_str::<SdkProjectBuildIncoming>(&value)?;
+                Ok(Box::new(model))
+            }
             ClientMethodsKey::SdkProjectFormat => {
                 print_debug!("> SdkProjectFormat: {}", value);
                 let model = serde_json::from_str::<SdkProjectFormatIncoming>(&value)?;
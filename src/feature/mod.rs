@@ -12,6 +12,10 @@ pub mod app_auth_login {
 pub mod app_auth_logout {
     pub mod incoming;
 }
+pub mod app_doctor {
+    pub mod incoming;
+    pub mod outgoing;
+}
 pub mod app_info {
     pub mod incoming;
     pub mod outgoing;
@@ -22,6 +26,10 @@ pub mod app_open_dir {
 pub mod app_open_file {
     pub mod incoming;
 }
+pub mod app_updates {
+    pub mod incoming;
+    pub mod outgoing;
+}
 pub mod demo_app_info {
     pub mod incoming;
     pub mod outgoing;
@@ -107,11 +115,18 @@ pub mod flutter_install {
 }
 pub mod flutter_project_format {
     pub mod incoming;
+    pub mod outgoing;
+}
+pub mod flutter_project_mirror {
+    pub mod incoming;
 }
 pub mod flutter_project_report {
     pub mod incoming;
     pub mod outgoing;
 }
+pub mod flutter_project_run {
+    pub mod incoming;
+}
 pub mod flutter_sync {
     pub mod incoming;
 }
@@ -121,6 +136,28 @@ pub mod flutter_terminal {
 pub mod flutter_uninstall {
     pub mod incoming;
 }
+pub mod flutter_use {
+    pub mod incoming;
+}
+pub mod package_diff {
+    pub mod incoming;
+    pub mod outgoing;
+}
+pub mod package_inspect {
+    pub mod incoming;
+    pub mod outgoing;
+}
+pub mod project_lint {
+    pub mod incoming;
+    pub mod outgoing;
+}
+pub mod project_new {
+    pub mod incoming;
+}
+pub mod project_validate {
+    pub mod incoming;
+    pub mod outgoing;
+}
 pub mod psdk_available {
     pub mod incoming;
     pub mod outgoing;
@@ -138,9 +175,19 @@ pub mod psdk_install {
 pub mod psdk_package_sign {
     pub mod incoming;
 }
+pub mod psdk_package_validate {
+    pub mod incoming;
+    pub mod outgoing;
+}
+pub mod psdk_project_ide {
+    pub mod incoming;
+}
 pub mod psdk_sync {
     pub mod incoming;
 }
+pub mod psdk_target_install {
+    pub mod incoming;
+}
 pub mod psdk_target_package_find {
     pub mod incoming;
     pub mod outgoing;
@@ -151,9 +198,15 @@ pub mod psdk_target_package_install {
 pub mod psdk_target_package_uninstall {
     pub mod incoming;
 }
+pub mod psdk_target_uninstall {
+    pub mod incoming;
+}
 pub mod psdk_terminal {
     pub mod incoming;
 }
+pub mod psdk_tooling_uninstall {
+    pub mod incoming;
+}
 pub mod psdk_uninstall {
     pub mod incoming;
 }
@@ -164,6 +217,16 @@ pub mod sdk_available {
 pub mod sdk_download {
     pub mod incoming;
 }
+pub mod sdk_engine_start {
+    pub mod incoming;
+}
+pub mod sdk_engine_status {
+    pub mod incoming;
+    pub mod outgoing;
+}
+pub mod sdk_engine_stop {
+    pub mod incoming;
+}
 pub mod sdk_ide_close {
     pub mod incoming;
 }
@@ -177,8 +240,13 @@ pub mod sdk_info {
 pub mod sdk_install {
     pub mod incoming;
 }
+pub mod sdk_project_build {
+    pub mod incoming;
+    pub mod outgoing;
+}
 pub mod sdk_project_format {
     pub mod incoming;
+    pub mod outgoing;
 }
 pub mod sdk_sync {
     pub mod incoming;
@@ -208,7 +276,9 @@ pub mod selector {
         pub mod select_flutter_installed;
         pub mod select_psdk_available;
         pub mod select_psdk_installed;
+        pub mod select_psdk_tarball;
         pub mod select_psdk_target;
+        pub mod select_psdk_tooling;
         pub mod select_sdk_available;
         pub mod select_sdk_installed;
     }
@@ -238,9 +308,11 @@ pub enum ClientMethodsState {
 pub enum ClientMethodsKey {
     AppAuthLogin,
     AppAuthLogout,
+    AppDoctor,
     AppInfo,
     AppOpenDir,
     AppOpenFile,
+    AppUpdates,
     DemoAppInfo,
     DeviceInfo,
     DevicePackageInstall,
@@ -267,27 +339,44 @@ pub enum ClientMethodsKey {
     FlutterInfo,
     FlutterInstall,
     FlutterProjectFormat,
+    FlutterProjectMirror,
     FlutterProjectReport,
+    FlutterProjectRun,
     FlutterSync,
     FlutterTerminal,
     FlutterUninstall,
+    FlutterUse,
+    PackageDiff,
+    PackageInspect,
+    ProjectLint,
+    ProjectNew,
+    ProjectValidate,
     PsdkAvailable,
     PsdkDownload,
     PsdkInfo,
     PsdkInstall,
     PsdkPackageSign,
+    PsdkPackageValidate,
+    PsdkProjectIde,
     PsdkSync,
+    PsdkTargetInstall,
     PsdkTargetPackageFind,
     PsdkTargetPackageInstall,
     PsdkTargetPackageUninstall,
+    PsdkTargetUninstall,
     PsdkTerminal,
+    PsdkToolingUninstall,
     PsdkUninstall,
     SdkAvailable,
     SdkDownload,
+    SdkEngineStart,
+    SdkEngineStatus,
+    SdkEngineStop,
     SdkIdeClose,
     SdkIdeOpen,
     SdkInfo,
     SdkInstall,
+    SdkProjectBuild,
     SdkProjectFormat,
     SdkSync,
     SdkTerminal,
@@ -22,6 +22,11 @@ pub trait TraitOutgoing {
     /// Serialize data
     fn to_json(&self) -> String;
 
+    /// Cli exit with error code after print
+    fn is_failed(&self) -> bool {
+        false
+    }
+
     /// Send by type interface
     fn send(&self, send_type: &OutgoingType) {
         match send_type {
@@ -0,0 +1,69 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+use crate::tools::rpm_utils;
+use crate::tools::utils;
+
+use super::outgoing::PackageDiffOutgoing;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PackageDiffIncoming {
+    old: PathBuf,
+    new: PathBuf,
+}
+
+impl PackageDiffIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::PackageDiff)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(old: PathBuf, new: PathBuf) -> Box<PackageDiffIncoming> {
+        Box::new(Self { old, new })
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("old", "new"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (old, new): (String, String)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(old)),
+                    utils::path_to_absolute(&PathBuf::from(new)),
+                ) {
+                    (Some(old), Some(new)) => Self::new(old, new).run(OutgoingType::Dbus),
+                    _ => StateMessageOutgoing::new_error(tr!("проверьте пути к пакетам")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(old: &Path, new: &Path) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let old = rpm_utils::get_info(old)?;
+        let new = rpm_utils::get_info(new)?;
+        Ok(PackageDiffOutgoing::new(rpm_utils::diff_info(&old, &new)))
+    }
+}
+
+impl TraitIncoming for PackageDiffIncoming {
+    fn run(&self, _: OutgoingType) -> Box<dyn TraitOutgoing> {
+        match Self::run(&self.old, &self.new) {
+            Ok(result) => result,
+            Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use colored::Colorize;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::tools::macros::print_info;
+use crate::tools::macros::print_success;
+use crate::tools::macros::tr;
+use crate::tools::rpm_utils::RpmDiff;
+
+use super::incoming::PackageDiffIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PackageDiffOutgoing {
+    diff: RpmDiff,
+}
+
+impl PackageDiffOutgoing {
+    pub fn new(diff: RpmDiff) -> Box<PackageDiffOutgoing> {
+        Box::new(Self { diff })
+    }
+}
+
+impl TraitOutgoing for PackageDiffOutgoing {
+    fn print(&self) {
+        let diff = &self.diff;
+        let message = format!("{} -> {}", diff.old.bold().white(), diff.new.bold().white());
+        print_info!(message);
+        if diff.is_empty() {
+            let message = tr!("пакеты не отличаются");
+            print_success!(message);
+            return;
+        }
+        for change in &diff.fields {
+            println!("{}: {} -> {}", change.name.bold(), change.old.red(), change.new.green());
+        }
+        for (title, added, removed) in [
+            ("Files", &diff.files_added, &diff.files_removed),
+            ("Requires", &diff.requires_added, &diff.requires_removed),
+            ("Provides", &diff.provides_added, &diff.provides_removed),
+        ] {
+            if added.is_empty() && removed.is_empty() {
+                continue;
+            }
+            println!("{}", format!("{}:", title).bold());
+            for value in removed {
+                println!("{}", format!("- {}", value).red());
+            }
+            for value in added {
+                println!("{}", format!("+ {}", value).green());
+            }
+        }
+        if !diff.files_changed.is_empty() {
+            println!("{}", "Files changed:".bold());
+            for change in &diff.files_changed {
+                println!("  {}: {} -> {}", change.name, change.old.red(), change.new.green());
+            }
+        }
+        if !diff.scriptlets_changed.is_empty() {
+            println!("{}", "Scriptlets:".bold());
+            for change in &diff.scriptlets_changed {
+                println!("  {}", change.name.cyan());
+                for line in change.old.lines() {
+                    println!("{}", format!("- {}", line).red());
+                }
+                for line in change.new.lines() {
+                    println!("{}", format!("+ {}", line).green());
+                }
+            }
+        }
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(PackageDiffIncoming::name(), self.clone())
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+use crate::tools::rpm_utils;
+use crate::tools::utils;
+
+use super::outgoing::PackageInspectOutgoing;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PackageInspectIncoming {
+    path: PathBuf,
+}
+
+impl PackageInspectIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::PackageInspect)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(path: PathBuf) -> Box<PackageInspectIncoming> {
+        Box::new(Self { path })
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к пакету")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(path: &Path) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        Ok(PackageInspectOutgoing::new(rpm_utils::get_info(path)?))
+    }
+}
+
+impl TraitIncoming for PackageInspectIncoming {
+    fn run(&self, _: OutgoingType) -> Box<dyn TraitOutgoing> {
+        match Self::run(&self.path) {
+            Ok(result) => result,
+            Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use colored::Colorize;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::tools::macros::print_info;
+use crate::tools::macros::tr;
+use crate::tools::rpm_utils::RpmInfo;
+
+use super::incoming::PackageInspectIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PackageInspectOutgoing {
+    package: RpmInfo,
+}
+
+impl PackageInspectOutgoing {
+    pub fn new(package: RpmInfo) -> Box<PackageInspectOutgoing> {
+        Box::new(Self { package })
+    }
+}
+
+impl TraitOutgoing for PackageInspectOutgoing {
+    fn print(&self) {
+        let package = &self.package;
+        let message = format!(
+            "Пакет: {}\nОписание: {}\nЛицензия: {}\nРазмер: {}\nПодпись: {}",
+            package.full_name().bold().white(),
+            package.summary.bold().white(),
+            package.license.bold().white(),
+            package.size.to_string().bold().white(),
+            if package.is_signed {
+                tr!("есть")
+            } else {
+                tr!("нет")
+            }
+            .bold()
+            .white(),
+        );
+        print_info!(message);
+        for (title, values) in [("Requires", &package.requires), ("Provides", &package.provides)] {
+            println!("{}", format!("{} ({}):", title, values.len()).bold());
+            for value in values {
+                println!("  {}", value);
+            }
+        }
+        if !package.scriptlets.is_empty() {
+            println!("{}", "Scriptlets:".bold());
+            for scriptlet in &package.scriptlets {
+                match &scriptlet.program {
+                    Some(program) => println!("  {} ({})", scriptlet.name.cyan(), program),
+                    None => println!("  {}", scriptlet.name.cyan()),
+                }
+                for line in scriptlet.script.lines() {
+                    println!("    {}", line);
+                }
+            }
+        }
+        println!("{}", format!("Files ({}):", package.files.len()).bold());
+        for file in &package.files {
+            println!(
+                "  {} {:<8} {:<8} {:>10} {}",
+                file.mode, file.user, file.group, file.size, file.path
+            );
+        }
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(PackageInspectIncoming::name(), self.clone())
+    }
+}
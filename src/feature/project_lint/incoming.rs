@@ -0,0 +1,64 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::lint_utils;
+use crate::tools::macros::tr;
+use crate::tools::utils;
+
+use super::outgoing::ProjectLintOutgoing;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProjectLintIncoming {
+    path: PathBuf,
+}
+
+impl ProjectLintIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::ProjectLint)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(path: PathBuf) -> Box<ProjectLintIncoming> {
+        Box::new(Self { path })
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(path: &Path) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let findings = lint_utils::lint_project(path)?;
+        Ok(ProjectLintOutgoing::new(findings))
+    }
+}
+
+impl TraitIncoming for ProjectLintIncoming {
+    fn run(&self, _: OutgoingType) -> Box<dyn TraitOutgoing> {
+        match Self::run(&self.path) {
+            Ok(result) => result,
+            Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+        }
+    }
+}
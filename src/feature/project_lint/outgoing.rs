@@ -0,0 +1,68 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::tools::lint_utils::LintFinding;
+use crate::tools::lint_utils::LintLevel;
+use crate::tools::macros::print_error;
+use crate::tools::macros::print_success;
+use crate::tools::macros::print_warning;
+use crate::tools::macros::tr;
+
+use super::incoming::ProjectLintIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProjectLintOutgoing {
+    passed: bool,
+    count_errors: usize,
+    count_warnings: usize,
+    findings: Vec<LintFinding>,
+}
+
+impl ProjectLintOutgoing {
+    pub fn new(findings: Vec<LintFinding>) -> Box<ProjectLintOutgoing> {
+        let count_errors = findings.iter().filter(|e| e.level == LintLevel::Error).count();
+        Box::new(Self {
+            passed: count_errors == 0,
+            count_errors,
+            count_warnings: findings.len() - count_errors,
+            findings,
+        })
+    }
+}
+
+impl TraitOutgoing for ProjectLintOutgoing {
+    fn print(&self) {
+        for finding in &self.findings {
+            let message = format!("{}: {} [{}]", finding.location(), finding.message, finding.rule);
+            match finding.level {
+                LintLevel::Error => print_error!(message),
+                LintLevel::Warning => print_warning!(message),
+            }
+        }
+        if self.passed {
+            let message = if self.count_warnings == 0 {
+                tr!("проблем не найдено")
+            } else {
+                tr!("ошибок не найдено, предупреждений: {}", self.count_warnings)
+            };
+            print_success!(message);
+        } else {
+            let message = tr!(
+                "найдено ошибок: {}, предупреждений: {}",
+                self.count_errors,
+                self.count_warnings
+            );
+            print_error!(message);
+        }
+    }
+
+    fn is_failed(&self) -> bool {
+        !self.passed
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(ProjectLintIncoming::name(), self.clone())
+    }
+}
@@ -0,0 +1,79 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+use crate::tools::project_utils;
+use crate::tools::project_utils::ProjectName;
+use crate::tools::utils;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProjectNewIncoming {
+    template: String,
+    name: String,
+    path: PathBuf,
+}
+
+impl ProjectNewIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::ProjectNew)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(template: String, name: String, path: PathBuf) -> Box<ProjectNewIncoming> {
+        Box::new(Self { template, name, path })
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("template", "name", "path"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (template, name, path): (String, String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new(template, name, path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к директории")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(template: &str, name: &str, path: &Path) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        if !project_utils::TEMPLATES.contains(&template) {
+            Err(tr!(
+                "неизвестный шаблон, доступные: {}",
+                project_utils::TEMPLATES.join(", ")
+            ))?
+        }
+        if !path.is_dir() {
+            Err(tr!("укажите директорию для проекта"))?
+        }
+        let name = ProjectName::parse(name, template)?;
+        let project = project_utils::gen_project(path, template, &name)?;
+        Ok(StateMessageOutgoing::new_success(tr!(
+            "проект {} создан: {}",
+            name.package,
+            project.to_string_lossy()
+        )))
+    }
+}
+
+impl TraitIncoming for ProjectNewIncoming {
+    fn run(&self, _: OutgoingType) -> Box<dyn TraitOutgoing> {
+        match Self::run(&self.template, &self.name, &self.path) {
+            Ok(result) => result,
+            Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::tools::macros::print_error;
+use crate::tools::macros::print_success;
+use crate::tools::macros::print_warning;
+use crate::tools::macros::tr;
+use crate::tools::validate_utils::ValidateCheck;
+
+use super::incoming::ProjectValidateIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProjectValidateOutgoing {
+    passed: bool,
+    count_failed: usize,
+    count_warnings: usize,
+    checks: Vec<ValidateCheck>,
+}
+
+impl ProjectValidateOutgoing {
+    pub fn new(checks: Vec<ValidateCheck>) -> Box<ProjectValidateOutgoing> {
+        let count_failed = checks.iter().filter(|e| !e.passed).count();
+        let count_warnings = checks.iter().filter(|e| e.warning).count();
+        Box::new(Self {
+            passed: count_failed == 0,
+            count_failed,
+            count_warnings,
+            checks,
+        })
+    }
+}
+
+impl TraitOutgoing for ProjectValidateOutgoing {
+    fn print(&self) {
+        for check in &self.checks {
+            let message = format!("{}: {}", check.file, check.message);
+            if check.warning {
+                print_warning!(message);
+            } else if check.passed {
+                print_success!(message);
+            } else {
+                print_error!(message);
+            }
+        }
+        if self.passed {
+            let message = if self.count_warnings == 0 {
+                tr!("проверка пройдена")
+            } else {
+                tr!("проверка пройдена, предупреждений: {}", self.count_warnings)
+            };
+            print_success!(message);
+        } else {
+            let message = tr!("проверка не пройдена, ошибок: {}", self.count_failed);
+            print_error!(message);
+        }
+    }
+
+    fn is_failed(&self) -> bool {
+        !self.passed
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(ProjectValidateIncoming::name(), self.clone())
+    }
+}
@@ -0,0 +1,206 @@
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_psdk_installed::PsdkInstalledModelSelect;
+use crate::feature::selector::selects::select_psdk_target::PsdkTargetModelSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::psdk_installed::model::PsdkInstalledModel;
+use crate::models::psdk_package_validate::model::PsdkPackageValidateModel;
+use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::service::command;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+use crate::tools::utils;
+
+use super::outgoing::PsdkPackageValidateOutgoing;
+
+/// Profile validation for publication in store
+const PROFILE: &str = "regular";
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PsdkPackageValidateIncoming {
+    id: Option<String>,
+    target_id: Option<String>,
+    path: PathBuf,
+}
+
+impl PsdkPackageValidateIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::PsdkPackageValidate)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(path: PathBuf) -> Box<PsdkPackageValidateIncoming> {
+        Box::new(Self {
+            id: None,
+            target_id: None,
+            path,
+        })
+    }
+
+    pub fn new_id(path: PathBuf, id: String) -> Box<PsdkPackageValidateIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: None,
+            path,
+        })
+    }
+
+    pub fn new_target_id(path: PathBuf, target_id: String, id: String) -> Box<PsdkPackageValidateIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: Some(target_id),
+            path,
+        })
+    }
+
+    fn select(&self, id: String) -> PsdkPackageValidateIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    fn select_with_target(&self, id: String, target_id: String) -> PsdkPackageValidateIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.target_id = Some(target_id);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к файлу")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("path", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, id): (String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_id(path, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к файлу")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_target_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "TargetById"),
+            ("path", "target_id", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, target_id, id): (String, String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_target_id(path, target_id, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к файлу")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(
+        model: PsdkInstalledModel,
+        target: PsdkTargetModel,
+        path: &PathBuf,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let package_name = match utils::get_package_name(path) {
+            Some(value) => value,
+            None => Err(tr!("необходимо указать путь к RPM пакету"))?,
+        };
+        StateMessageOutgoing::new_state(tr!("валидация пакета {}", package_name)).send(send_type);
+        let (success, lines) = command::psdk::rpm_validate(&model.chroot, &target, path, PROFILE)?;
+        let checks = PsdkPackageValidateModel::parse(&lines);
+        // Exit 0 without findings - package is valid
+        if !success && checks.is_empty() {
+            match lines.last() {
+                Some(line) => Err(tr!("rpm-validator завершился с ошибкой: {}", line))?,
+                None => Err(tr!("rpm-validator завершился с ошибкой"))?,
+            }
+        }
+        Ok(PsdkPackageValidateOutgoing::new(
+            package_name,
+            target.full_name,
+            PROFILE.to_string(),
+            success,
+            checks,
+        ))
+    }
+}
+
+impl TraitIncoming for PsdkPackageValidateIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Find psdk
+        let key = PsdkPackageValidateIncoming::name();
+        let models = PsdkInstalledModelSelect::search(&self.id, tr!("получаем информацию о Platform SDK"), &send_type);
+        // Select psdk
+        match models.iter().count() {
+            1 => {
+                let model = models.first().unwrap().clone();
+                if !self.path.is_file() {
+                    return StateMessageOutgoing::new_error(tr!("необходимо указать путь к файлу"));
+                }
+                let package_arch = match utils::get_package_arch(&self.path) {
+                    Some(value) => value,
+                    None => return StateMessageOutgoing::new_error(tr!("необходимо указать путь к RPM пакету")),
+                };
+                // Find psdk targets by arch package
+                let targets = PsdkTargetModelSelect::search(
+                    &self.target_id,
+                    tr!("получаем информацию о Platform Target"),
+                    &send_type,
+                    model
+                        .targets
+                        .iter()
+                        .filter(|e| e.arch == package_arch)
+                        .cloned()
+                        .collect::<Vec<PsdkTargetModel>>(),
+                );
+                // Select psdk targets
+                match targets.iter().count() {
+                    1 => match Self::run(model.clone(), targets.first().unwrap().clone(), &self.path, &send_type) {
+                        Ok(result) => result,
+                        Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+                    },
+                    0 => StateMessageOutgoing::new_error(tr!(
+                        "Platform Target с архитектурой {} не найден",
+                        package_arch
+                    )),
+                    _ => match PsdkTargetModelSelect::select(key, &send_type, targets, |id| {
+                        self.select_with_target(model.id.clone(), id)
+                    }) {
+                        Ok(value) => Box::new(value),
+                        Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform Target")),
+                    },
+                }
+            }
+            0 => StateMessageOutgoing::new_info(tr!("Platform SDK не найдены")),
+            _ => match PsdkInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform SDK")),
+            },
+        }
+    }
+}
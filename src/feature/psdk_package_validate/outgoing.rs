@@ -0,0 +1,72 @@
+use colored::Colorize;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::models::TraitModel;
+use crate::models::psdk_package_validate::model::PsdkPackageValidateModel;
+use crate::tools::macros::print_error;
+use crate::tools::macros::print_info;
+use crate::tools::macros::print_success;
+use crate::tools::macros::tr;
+
+use super::incoming::PsdkPackageValidateIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PsdkPackageValidateOutgoing {
+    package: String,
+    target: String,
+    profile: String,
+    passed: bool,
+    count_errors: usize,
+    count_warnings: usize,
+    checks: Vec<PsdkPackageValidateModel>,
+}
+
+impl PsdkPackageValidateOutgoing {
+    pub fn new(
+        package: String,
+        target: String,
+        profile: String,
+        passed: bool,
+        checks: Vec<PsdkPackageValidateModel>,
+    ) -> Box<PsdkPackageValidateOutgoing> {
+        let count_errors = checks.iter().map(|e| e.errors.len()).sum();
+        let count_warnings = checks.iter().map(|e| e.warnings.len()).sum();
+        Box::new(Self {
+            package,
+            target,
+            profile,
+            passed,
+            count_errors,
+            count_warnings,
+            checks,
+        })
+    }
+}
+
+impl TraitOutgoing for PsdkPackageValidateOutgoing {
+    fn print(&self) {
+        let message = format!(
+            "Package: {}\nPlatform Target: {}\nProfile: {}",
+            self.package.bold().white(),
+            self.target.bold().white(),
+            self.profile.bold().white(),
+        );
+        print_info!(message);
+        for item in &self.checks {
+            item.print();
+        }
+        let result = tr!("ошибок: {}, предупреждений: {}", self.count_errors, self.count_warnings);
+        if self.passed {
+            print_success!(result);
+        } else {
+            print_error!(result);
+        }
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(PsdkPackageValidateIncoming::name(), self.clone())
+    }
+}
@@ -0,0 +1,191 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_psdk_installed::PsdkInstalledModelSelect;
+use crate::feature::selector::selects::select_psdk_target::PsdkTargetModelSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::psdk_installed::model::PsdkInstalledModel;
+use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::service::command;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::ide_utils;
+use crate::tools::macros::tr;
+use crate::tools::utils;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PsdkProjectIdeIncoming {
+    id: Option<String>,
+    target_id: Option<String>,
+    path: PathBuf,
+}
+
+impl PsdkProjectIdeIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::PsdkProjectIde)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(path: PathBuf) -> Box<PsdkProjectIdeIncoming> {
+        Box::new(Self {
+            id: None,
+            target_id: None,
+            path,
+        })
+    }
+
+    pub fn new_id(path: PathBuf, id: String) -> Box<PsdkProjectIdeIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: None,
+            path,
+        })
+    }
+
+    pub fn new_target_id(path: PathBuf, target_id: String, id: String) -> Box<PsdkProjectIdeIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: Some(target_id),
+            path,
+        })
+    }
+
+    fn select(&self, id: String) -> PsdkProjectIdeIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    fn select_with_target(&self, id: String, target_id: String) -> PsdkProjectIdeIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.target_id = Some(target_id);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("path", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, id): (String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_id(path, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_target_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "TargetById"),
+            ("path", "target_id", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, target_id, id): (String, String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_target_id(path, target_id, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(
+        model: PsdkInstalledModel,
+        target: PsdkTargetModel,
+        path: &Path,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        if !path.join("CMakeLists.txt").exists() {
+            Err(tr!("поддерживаются только проекты CMake"))?
+        }
+        // Configure project in target
+        StateMessageOutgoing::new_state(tr!("конфигурация CMake для {}", target.full_name)).send(send_type);
+        let build_dir = ide_utils::get_build_dir(path, &target);
+        command::psdk::project_configure(&model.chroot, &target, path, &build_dir)?;
+        // Generate files
+        StateMessageOutgoing::new_state(tr!("генерация файлов IDE")).send(send_type);
+        let result = ide_utils::gen_ide_files(path, &target)?;
+        StateMessageOutgoing::new_info(tr!("CMake toolchain: {}", result.toolchain.to_string_lossy())).send(send_type);
+        StateMessageOutgoing::new_info(tr!(
+            "compile_commands.json: {}",
+            result.compile_commands.to_string_lossy()
+        ))
+        .send(send_type);
+        match result.clangd {
+            Some(clangd) => StateMessageOutgoing::new_info(tr!("clangd: {}", clangd.to_string_lossy())).send(send_type),
+            None => StateMessageOutgoing::new_warning(tr!("файл .clangd уже существует, пропущено")).send(send_type),
+        }
+        StateMessageOutgoing::new_info(tr!("VS Code: {}", result.vscode.to_string_lossy())).send(send_type);
+        Ok(StateMessageOutgoing::new_success(tr!(
+            "файлы IDE для {} успешно созданы",
+            target.full_name
+        )))
+    }
+}
+
+impl TraitIncoming for PsdkProjectIdeIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Find psdk
+        let key = PsdkProjectIdeIncoming::name();
+        let models = PsdkInstalledModelSelect::search(&self.id, tr!("получаем информацию о Platform SDK"), &send_type);
+        // Select psdk
+        match models.iter().count() {
+            1 => {
+                // Find psdk targets
+                let model = models.first().unwrap().clone();
+                let targets = PsdkTargetModelSelect::search(
+                    &self.target_id,
+                    tr!("получаем информацию о Platform Target"),
+                    &send_type,
+                    model.targets.clone(),
+                );
+                // Select psdk targets
+                match targets.iter().count() {
+                    1 => match Self::run(model, targets.first().unwrap().clone(), &self.path, &send_type) {
+                        Ok(result) => result,
+                        Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+                    },
+                    0 => StateMessageOutgoing::new_info(tr!("Platform Target не найдены")),
+                    _ => match PsdkTargetModelSelect::select(key, &send_type, targets, |id| {
+                        self.select_with_target(model.id.clone(), id)
+                    }) {
+                        Ok(value) => Box::new(value),
+                        Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform Target")),
+                    },
+                }
+            }
+            0 => StateMessageOutgoing::new_info(tr!("Platform SDK не найдены")),
+            _ => match PsdkInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform SDK")),
+            },
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_psdk_available::PsdkAvailableModelSelect;
+use crate::feature::selector::selects::select_psdk_installed::PsdkInstalledModelSelect;
+use crate::feature::selector::selects::select_psdk_tarball::PsdkTarballSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::TraitModel;
+use crate::models::configuration::Config;
+use crate::models::configuration::psdk::PsdkConfig;
+use crate::models::psdk_available::model::PsdkAvailableModel;
+use crate::models::psdk_installed::model::PsdkInstalledModel;
+use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::models::psdk_tooling::model::PsdkToolingModel;
+use crate::service::command;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+use crate::tools::single;
+use crate::tools::utils;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PsdkTargetInstallIncoming {
+    id: Option<String>,
+    available_id: Option<String>,
+    url: Option<String>,
+    path: Option<PathBuf>,
+}
+
+impl PsdkTargetInstallIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::PsdkTargetInstall)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new() -> Box<PsdkTargetInstallIncoming> {
+        Box::new(Self {
+            id: None,
+            available_id: None,
+            url: None,
+            path: None,
+        })
+    }
+
+    pub fn new_id(id: String) -> Box<PsdkTargetInstallIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            available_id: None,
+            url: None,
+            path: None,
+        })
+    }
+
+    pub fn new_path(path: PathBuf) -> Box<PsdkTargetInstallIncoming> {
+        Box::new(Self {
+            id: None,
+            available_id: None,
+            url: None,
+            path: Some(path),
+        })
+    }
+
+    pub fn new_path_id(path: PathBuf, id: String) -> Box<PsdkTargetInstallIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            available_id: None,
+            url: None,
+            path: Some(path),
+        })
+    }
+
+    fn select(&self, id: String) -> PsdkTargetInstallIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    fn select_with_available(&self, id: String, available_id: String) -> PsdkTargetInstallIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.available_id = Some(available_id);
+        select
+    }
+
+    fn select_with_url(&self, id: String, available_id: String, url: String) -> PsdkTargetInstallIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.available_id = Some(available_id);
+        select.url = Some(url);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            (),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (): ()| async move {
+                let outgoing = Self::new().run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("id",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (id,): (String,)| async move {
+                let outgoing = Self::new_id(id).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_path(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "Path"),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_path(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к файлу")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_path_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "PathById"),
+            ("path", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, id): (String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_path_id(path, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к файлу")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run_available(&self, model: PsdkInstalledModel, send_type: &OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Find available psdk
+        let key = PsdkTargetInstallIncoming::name();
+        let availables = PsdkAvailableModelSelect::search(&self.available_id, tr!("получаем список..."), send_type);
+        // Select available psdk
+        match availables.iter().count() {
+            1 => {
+                let available = availables.first().unwrap().clone();
+                let urls = PsdkTarballSelect::search(&self.url, available.get_urls_targets());
+                // Select target tarball
+                match urls.iter().count() {
+                    1 => match Self::run_url(model, available, urls.first().unwrap().clone(), send_type) {
+                        Ok(result) => result,
+                        Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+                    },
+                    0 => StateMessageOutgoing::new_info(tr!("Platform Target не найдены")),
+                    _ => match PsdkTarballSelect::select(key, send_type, urls, |url| {
+                        self.select_with_url(model.id.clone(), available.get_id(), url)
+                    }) {
+                        Ok(value) => Box::new(value),
+                        Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform Target")),
+                    },
+                }
+            }
+            0 => StateMessageOutgoing::new_info(tr!("не удалось получить данные")),
+            _ => match PsdkAvailableModelSelect::select(key, send_type, availables, |id| {
+                self.select_with_available(model.id.clone(), id)
+            }) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform SDK")),
+            },
+        }
+    }
+
+    fn run_url(
+        model: PsdkInstalledModel,
+        available: PsdkAvailableModel,
+        url: String,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        StateMessageOutgoing::new_state(tr!("начинаем загрузку...")).send(send_type);
+        let path = Self::download(url, send_type)?;
+        Self::run(model, path, available.get_url_tooling(), send_type)
+    }
+
+    fn run_path(
+        model: PsdkInstalledModel,
+        path: &PathBuf,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        if !path.is_file() {
+            Err(tr!("необходимо указать путь к файлу"))?
+        }
+        let path_psdk = command::psdk::add_temp_file_flatpak_for_psdk(path);
+        let result = Self::run(model, path_psdk.clone(), None, send_type);
+        command::psdk::del_temp_file_flatpak_for_psdk(&path_psdk);
+        result
+    }
+
+    fn download(url: String, send_type: &OutgoingType) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let path =
+            single::get_request().download_file(url, StateMessageOutgoing::get_state_callback_file_big(send_type))?;
+        match utils::move_to_downloads(vec![path])?.first() {
+            Some(value) => Ok(value.clone()),
+            None => Err(tr!("не удалось загрузить файл"))?,
+        }
+    }
+
+    fn run(
+        model: PsdkInstalledModel,
+        path: PathBuf,
+        url_tooling: Option<String>,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        // Time start
+        let start = SystemTime::now();
+        // Get data from name tarball
+        let version = match PsdkAvailableModel::get_tarball_version(&path) {
+            Some(value) => value,
+            None => Err(tr!("не удалось получить версию из названия архива"))?,
+        };
+        let tooling = format!("AuroraOS-{}-base", &version);
+        let is_tooling = path
+            .file_name()
+            .is_some_and(|e| e.to_string_lossy().contains("Tooling"));
+        let target = if is_tooling {
+            None
+        } else {
+            match PsdkAvailableModel::get_tarball_arch(&path).and_then(|e| PsdkTargetModel::get_arch(&e)) {
+                Some(arch) => Some((format!("{}-{}", &tooling, &arch), arch)),
+                None => Err(tr!("не удалось получить архитектуру из названия архива"))?,
+            }
+        };
+        let is_tooling_installed = model.toolings.iter().any(|e| e.name == tooling);
+        // Check installed
+        let installed = match &target {
+            Some((name, _)) => model.targets.iter().any(|e| &e.full_name == name),
+            None => is_tooling_installed,
+        };
+        if installed {
+            return Ok(StateMessageOutgoing::new_info(tr!(
+                "{} уже установлен",
+                if target.is_none() { "Tooling" } else { "Target" }
+            )));
+        }
+        // Install tooling
+        if !is_tooling_installed {
+            let path_tooling = match (&target, url_tooling) {
+                (None, _) => path.clone(),
+                (Some(_), Some(url)) => {
+                    StateMessageOutgoing::new_state(tr!("загрузка Tooling: {}", &tooling)).send(send_type);
+                    Self::download(url, send_type)?
+                }
+                (Some(_), None) => Err(tr!("не найден Tooling {}, сначала установите его", &tooling))?,
+            };
+            StateMessageOutgoing::new_state(tr!("установка Tooling")).send(send_type);
+            command::psdk::tooling_create(
+                &model.chroot,
+                &tooling,
+                &path_tooling,
+                StateMessageOutgoing::get_state_callback_count(4, send_type),
+            )?;
+            StateMessageOutgoing::new_progress("100".into()).send(send_type);
+        }
+        // Install target
+        if let Some((name, arch)) = &target {
+            StateMessageOutgoing::new_state(tr!("установка Target: {}", arch)).send(send_type);
+            command::psdk::target_create(
+                &model.chroot,
+                name,
+                &tooling,
+                &path,
+                StateMessageOutgoing::get_state_callback_count(9, send_type),
+            )?;
+            StateMessageOutgoing::new_progress("100".into()).send(send_type);
+        }
+        // Check result
+        let (toolings, targets) = PsdkToolingModel::search_full(model.chroot.clone(), model.dir.clone())?;
+        let is_done = match &target {
+            Some((name, _)) => targets.iter().any(|e| &e.full_name == name),
+            None => toolings.iter().any(|e| e.name == tooling),
+        };
+        if !is_done {
+            Err(tr!("произошла ошибка при установке"))?
+        }
+
+        //////////
+        // SYNC
+        StateMessageOutgoing::new_state(tr!("запуск синхронизации Platform SDK")).send(send_type);
+        let _ = Config::save_psdk(PsdkConfig::search());
+
+        ///////
+        // DONE
+        let end = SystemTime::now();
+        let duration = end.duration_since(start).unwrap();
+        let seconds = duration.as_secs();
+        Ok(StateMessageOutgoing::new_success(tr!(
+            "установка {} успешно выполнена ({}s)",
+            if target.is_none() { "Tooling" } else { "Target" },
+            seconds
+        )))
+    }
+}
+
+impl TraitIncoming for PsdkTargetInstallIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Find psdk
+        let key = PsdkTargetInstallIncoming::name();
+        let models = PsdkInstalledModelSelect::search(&self.id, tr!("получаем информацию о Platform SDK"), &send_type);
+        // Select psdk
+        match models.iter().count() {
+            1 => {
+                let model = models.first().unwrap().clone();
+                match &self.path {
+                    Some(path) => match Self::run_path(model, path, &send_type) {
+                        Ok(result) => result,
+                        Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+                    },
+                    None => self.run_available(model, &send_type),
+                }
+            }
+            0 => StateMessageOutgoing::new_info(tr!("Platform SDK не найдены")),
+            _ => match PsdkInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform SDK")),
+            },
+        }
+    }
+}
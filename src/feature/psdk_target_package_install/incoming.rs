@@ -9,6 +9,7 @@ use crate::feature::incoming::TraitIncoming;
 use crate::feature::outgoing::OutgoingType;
 use crate::feature::outgoing::TraitOutgoing;
 use crate::feature::selector::selects::select_psdk_installed::PsdkInstalledModelSelect;
+use crate::feature::selector::selects::select_psdk_target::PsdkTargetModelSelect;
 use crate::feature::state_message::outgoing::StateMessageOutgoing;
 use crate::models::psdk_installed::model::PsdkInstalledModel;
 use crate::models::psdk_target::model::PsdkTargetModel;
@@ -21,6 +22,7 @@ use crate::tools::utils;
 #[derive(Serialize, Deserialize, Clone)]
 pub struct PsdkTargetPackageInstallIncoming {
     id: Option<String>,
+    target_id: Option<String>,
     path: PathBuf,
 }
 
@@ -32,11 +34,27 @@ impl PsdkTargetPackageInstallIncoming {
     }
 
     pub fn new(path: PathBuf) -> Box<PsdkTargetPackageInstallIncoming> {
-        Box::new(Self { id: None, path })
+        Box::new(Self {
+            id: None,
+            target_id: None,
+            path,
+        })
     }
 
     pub fn new_id(path: PathBuf, id: String) -> Box<PsdkTargetPackageInstallIncoming> {
-        Box::new(Self { id: Some(id), path })
+        Box::new(Self {
+            id: Some(id),
+            target_id: None,
+            path,
+        })
+    }
+
+    pub fn new_target_id(path: PathBuf, target_id: String, id: String) -> Box<PsdkTargetPackageInstallIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: Some(target_id),
+            path,
+        })
     }
 
     fn select(&self, id: String) -> PsdkTargetPackageInstallIncoming {
@@ -45,6 +63,13 @@ impl PsdkTargetPackageInstallIncoming {
         select
     }
 
+    fn select_with_target(&self, id: String, target_id: String) -> PsdkTargetPackageInstallIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.target_id = Some(target_id);
+        select
+    }
+
     pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
         builder.method_with_cr_async(
             Self::name(),
@@ -75,6 +100,21 @@ impl PsdkTargetPackageInstallIncoming {
         );
     }
 
+    pub fn dbus_method_run_target_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "TargetById"),
+            ("path", "target_id", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, target_id, id): (String, String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_target_id(path, target_id, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к файлу")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
     fn run(
         model: PsdkInstalledModel,
         target: PsdkTargetModel,
@@ -119,22 +159,34 @@ impl TraitIncoming for PsdkTargetPackageInstallIncoming {
                     Some(value) => value,
                     None => return StateMessageOutgoing::new_error(tr!("необходимо указать путь к RPM пакету")),
                 };
-                match model
-                    .targets
-                    .iter()
-                    .filter(|e| e.arch == package_arch)
-                    .cloned()
-                    .collect::<Vec<PsdkTargetModel>>()
-                    .first()
-                {
-                    Some(target) => match Self::run(models.first().unwrap().clone(), target.clone(), &self.path) {
+                // Find psdk targets by arch package
+                let targets = PsdkTargetModelSelect::search(
+                    &self.target_id,
+                    tr!("получаем информацию о Platform Target"),
+                    &send_type,
+                    model
+                        .targets
+                        .iter()
+                        .filter(|e| e.arch == package_arch)
+                        .cloned()
+                        .collect::<Vec<PsdkTargetModel>>(),
+                );
+                // Select psdk targets
+                match targets.iter().count() {
+                    1 => match Self::run(model.clone(), targets.first().unwrap().clone(), &self.path) {
                         Ok(result) => result,
                         Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
                     },
-                    None => StateMessageOutgoing::new_error(tr!(
+                    0 => StateMessageOutgoing::new_error(tr!(
                         "Platform Target с архитектурой {} не найден",
                         package_arch
                     )),
+                    _ => match PsdkTargetModelSelect::select(key, &send_type, targets, |id| {
+                        self.select_with_target(model.id.clone(), id)
+                    }) {
+                        Ok(value) => Box::new(value),
+                        Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform Target")),
+                    },
                 }
             }
             0 => StateMessageOutgoing::new_info(tr!("Platform SDK не найдены")),
@@ -0,0 +1,164 @@
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_psdk_installed::PsdkInstalledModelSelect;
+use crate::feature::selector::selects::select_psdk_target::PsdkTargetModelSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::configuration::Config;
+use crate::models::configuration::psdk::PsdkConfig;
+use crate::models::psdk_installed::model::PsdkInstalledModel;
+use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::service::command;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PsdkTargetUninstallIncoming {
+    id: Option<String>,
+    target_id: Option<String>,
+}
+
+impl PsdkTargetUninstallIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::PsdkTargetUninstall)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new() -> Box<PsdkTargetUninstallIncoming> {
+        Box::new(Self {
+            id: None,
+            target_id: None,
+        })
+    }
+
+    pub fn new_id(id: String) -> Box<PsdkTargetUninstallIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: None,
+        })
+    }
+
+    pub fn new_target_id(target_id: String, id: String) -> Box<PsdkTargetUninstallIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: Some(target_id),
+        })
+    }
+
+    fn select(&self, id: String) -> PsdkTargetUninstallIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    fn select_with_target(&self, id: String, target_id: String) -> PsdkTargetUninstallIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.target_id = Some(target_id);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            (),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (): ()| async move {
+                let outgoing = Self::new().run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("id",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (id,): (String,)| async move {
+                let outgoing = Self::new_id(id).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_target_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "TargetById"),
+            ("target_id", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (target_id, id): (String, String)| async move {
+                let outgoing = Self::new_target_id(target_id, id).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(
+        model: PsdkInstalledModel,
+        target: PsdkTargetModel,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        // Remove snapshots before target
+        for snapshot in &target.snapshots {
+            StateMessageOutgoing::new_state(tr!("удаление Snapshot: {}", snapshot)).send(send_type);
+            command::psdk::target_remove(&model.chroot, snapshot)?;
+        }
+        StateMessageOutgoing::new_state(tr!("удаление Target: {}", target.full_name)).send(send_type);
+        command::psdk::target_remove(&model.chroot, &target.full_name)?;
+        // Sync
+        StateMessageOutgoing::new_state(tr!("запуск синхронизации Platform SDK")).send(send_type);
+        let _ = Config::save_psdk(PsdkConfig::search());
+        // Done
+        Ok(StateMessageOutgoing::new_success(tr!(
+            "Target {} успешно удален",
+            target.full_name
+        )))
+    }
+}
+
+impl TraitIncoming for PsdkTargetUninstallIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Find psdk
+        let key = PsdkTargetUninstallIncoming::name();
+        let models = PsdkInstalledModelSelect::search(&self.id, tr!("получаем информацию о Platform SDK"), &send_type);
+        // Select psdk
+        match models.iter().count() {
+            1 => {
+                // Find psdk targets
+                let model = models.first().unwrap().clone();
+                let targets = PsdkTargetModelSelect::search(
+                    &self.target_id,
+                    tr!("получаем информацию о Platform Target"),
+                    &send_type,
+                    model.targets.clone(),
+                );
+                // Select psdk targets
+                match targets.iter().count() {
+                    1 => match Self::run(model, targets.first().unwrap().clone(), &send_type) {
+                        Ok(result) => result,
+                        Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+                    },
+                    0 => StateMessageOutgoing::new_info(tr!("Platform Target не найдены")),
+                    _ => match PsdkTargetModelSelect::select(key, &send_type, targets, |id| {
+                        self.select_with_target(model.id.clone(), id)
+                    }) {
+                        Ok(value) => Box::new(value),
+                        Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform Target")),
+                    },
+                }
+            }
+            0 => StateMessageOutgoing::new_info(tr!("Platform SDK не найдены")),
+            _ => match PsdkInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform SDK")),
+            },
+        }
+    }
+}
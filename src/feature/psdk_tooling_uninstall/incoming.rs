@@ -0,0 +1,168 @@
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_psdk_installed::PsdkInstalledModelSelect;
+use crate::feature::selector::selects::select_psdk_tooling::PsdkToolingModelSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::configuration::Config;
+use crate::models::configuration::psdk::PsdkConfig;
+use crate::models::psdk_installed::model::PsdkInstalledModel;
+use crate::models::psdk_tooling::model::PsdkToolingModel;
+use crate::service::command;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct PsdkToolingUninstallIncoming {
+    id: Option<String>,
+    tooling_id: Option<String>,
+}
+
+impl PsdkToolingUninstallIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::PsdkToolingUninstall)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new() -> Box<PsdkToolingUninstallIncoming> {
+        Box::new(Self {
+            id: None,
+            tooling_id: None,
+        })
+    }
+
+    pub fn new_id(id: String) -> Box<PsdkToolingUninstallIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            tooling_id: None,
+        })
+    }
+
+    pub fn new_tooling_id(tooling_id: String, id: String) -> Box<PsdkToolingUninstallIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            tooling_id: Some(tooling_id),
+        })
+    }
+
+    fn select(&self, id: String) -> PsdkToolingUninstallIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    fn select_with_tooling(&self, id: String, tooling_id: String) -> PsdkToolingUninstallIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.tooling_id = Some(tooling_id);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            (),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (): ()| async move {
+                let outgoing = Self::new().run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("id",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (id,): (String,)| async move {
+                let outgoing = Self::new_id(id).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_tooling_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ToolingById"),
+            ("tooling_id", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (tooling_id, id): (String, String)| async move {
+                let outgoing = Self::new_tooling_id(tooling_id, id).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(
+        model: PsdkInstalledModel,
+        tooling: PsdkToolingModel,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        // Tooling can't be removed while targets use it
+        for target in model.targets.iter().filter(|e| e.tooling == tooling.name) {
+            for snapshot in &target.snapshots {
+                StateMessageOutgoing::new_state(tr!("удаление Snapshot: {}", snapshot)).send(send_type);
+                command::psdk::target_remove(&model.chroot, snapshot)?;
+            }
+            StateMessageOutgoing::new_state(tr!("удаление Target: {}", target.full_name)).send(send_type);
+            command::psdk::target_remove(&model.chroot, &target.full_name)?;
+        }
+        StateMessageOutgoing::new_state(tr!("удаление Tooling: {}", tooling.name)).send(send_type);
+        command::psdk::tooling_remove(&model.chroot, &tooling.name)?;
+        // Sync
+        StateMessageOutgoing::new_state(tr!("запуск синхронизации Platform SDK")).send(send_type);
+        let _ = Config::save_psdk(PsdkConfig::search());
+        // Done
+        Ok(StateMessageOutgoing::new_success(tr!(
+            "Tooling {} успешно удален",
+            tooling.name
+        )))
+    }
+}
+
+impl TraitIncoming for PsdkToolingUninstallIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Find psdk
+        let key = PsdkToolingUninstallIncoming::name();
+        let models = PsdkInstalledModelSelect::search(&self.id, tr!("получаем информацию о Platform SDK"), &send_type);
+        // Select psdk
+        match models.iter().count() {
+            1 => {
+                // Find psdk toolings
+                let model = models.first().unwrap().clone();
+                let toolings = PsdkToolingModelSelect::search(
+                    &self.tooling_id,
+                    tr!("получаем информацию о Platform Tooling"),
+                    &send_type,
+                    model.toolings.clone(),
+                );
+                // Select psdk toolings
+                match toolings.iter().count() {
+                    1 => match Self::run(model, toolings.first().unwrap().clone(), &send_type) {
+                        Ok(result) => result,
+                        Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+                    },
+                    0 => StateMessageOutgoing::new_info(tr!("Platform Tooling не найдены")),
+                    _ => match PsdkToolingModelSelect::select(key, &send_type, toolings, |id| {
+                        self.select_with_tooling(model.id.clone(), id)
+                    }) {
+                        Ok(value) => Box::new(value),
+                        Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform Tooling")),
+                    },
+                }
+            }
+            0 => StateMessageOutgoing::new_info(tr!("Platform SDK не найдены")),
+            _ => match PsdkInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform SDK")),
+            },
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_sdk_installed::SdkInstalledModelSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::sdk_available::model::SdkBuildType;
+use crate::models::sdk_installed::model::SdkInstalledModel;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SdkEngineStartIncoming {
+    id: Option<String>,
+}
+
+impl SdkEngineStartIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::SdkEngineStart)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new() -> Box<SdkEngineStartIncoming> {
+        Box::new(Self { id: None })
+    }
+
+    pub fn new_id(id: String) -> Box<SdkEngineStartIncoming> {
+        Box::new(Self { id: Some(id) })
+    }
+
+    fn select(&self, id: String) -> SdkEngineStartIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            (),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (): ()| async move {
+                let outgoing = Self::new().run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("id",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (id,): (String,)| async move {
+                let outgoing = Self::new_id(id).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    fn run(
+        model: SdkInstalledModel,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let mut engine = model.get_sdk_engine()?;
+        if engine.is_running {
+            return Ok(StateMessageOutgoing::new_info(tr!("engine уже запущен")));
+        }
+        StateMessageOutgoing::new_state(tr!("запускаем engine...")).send(send_type);
+        engine.start()?;
+        // Wait ssh engine
+        let session = engine.session()?;
+        session.close()?;
+        Ok(StateMessageOutgoing::new_success(tr!("engine успешно запущен")))
+    }
+}
+
+impl TraitIncoming for SdkEngineStartIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Search
+        let key = SdkEngineStartIncoming::name();
+        let models: Vec<SdkInstalledModel> =
+            SdkInstalledModelSelect::search(&self.id, tr!("ищем Аврора SDK (MB2)"), &send_type)
+                .iter()
+                .filter(|e| e.build_type == SdkBuildType::MB2)
+                .cloned()
+                .collect();
+        // Select
+        match models.iter().count() {
+            1 => match Self::run(models.first().unwrap().clone(), &send_type) {
+                Ok(result) => result,
+                Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+            },
+            0 => StateMessageOutgoing::new_info(tr!("Аврора SDK не найдены")),
+            _ => match SdkInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Аврора SDK")),
+            },
+        }
+    }
+}
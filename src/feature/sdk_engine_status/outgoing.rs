@@ -0,0 +1,73 @@
+use colored::Colorize;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::tools::macros::print_info;
+
+use super::incoming::SdkEngineStatusIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SdkEngineStatusOutgoing {
+    name: String,
+    uuid: String,
+    is_running: bool,
+    // MB
+    memory: u64,
+    // MB
+    memory_used: Option<u64>,
+    // Seconds
+    uptime: Option<u64>,
+}
+
+impl SdkEngineStatusOutgoing {
+    pub fn new(
+        name: String,
+        uuid: String,
+        is_running: bool,
+        memory: u64,
+        memory_used: Option<u64>,
+        uptime: Option<u64>,
+    ) -> Box<SdkEngineStatusOutgoing> {
+        Box::new(Self {
+            name,
+            uuid,
+            is_running,
+            memory,
+            memory_used,
+            uptime,
+        })
+    }
+}
+
+impl TraitOutgoing for SdkEngineStatusOutgoing {
+    fn print(&self) {
+        let status = if self.is_running {
+            "активен"
+        } else {
+            "не активен"
+        };
+        let memory = match self.memory_used {
+            Some(used) => format!("{} / {} MB", used, self.memory),
+            None => format!("{} MB", self.memory),
+        };
+        let uptime = match self.uptime {
+            Some(seconds) => format!("{} ч {} мин", seconds / 3600, seconds % 3600 / 60),
+            None => "-".to_string(),
+        };
+        let message = format!(
+            "Engine: {}\nСтатус: {}\nПамять: {}\nВремя работы: {}\nUUID: {}",
+            self.name.bold().white(),
+            status.bold().white(),
+            memory.bold().white(),
+            uptime.bold().white(),
+            self.uuid.bold().white(),
+        );
+        print_info!(message);
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(SdkEngineStatusIncoming::name(), self.clone())
+    }
+}
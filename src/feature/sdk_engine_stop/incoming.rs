@@ -0,0 +1,138 @@
+use std::thread;
+use std::time::Duration;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_sdk_installed::SdkInstalledModelSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::sdk_available::model::SdkBuildType;
+use crate::models::sdk_installed::model::SdkInstalledModel;
+use crate::service::command::exec;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+use crate::tools::programs;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SdkEngineStopIncoming {
+    id: Option<String>,
+    is_wait_ide: bool,
+}
+
+impl SdkEngineStopIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::SdkEngineStop)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(is_wait_ide: bool) -> Box<SdkEngineStopIncoming> {
+        Box::new(Self { id: None, is_wait_ide })
+    }
+
+    pub fn new_id(is_wait_ide: bool, id: String) -> Box<SdkEngineStopIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            is_wait_ide,
+        })
+    }
+
+    fn select(&self, id: String) -> SdkEngineStopIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("is_wait_ide",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (is_wait_ide,): (bool,)| async move {
+                let outgoing = Self::new(is_wait_ide).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("is_wait_ide", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (is_wait_ide, id): (bool, String)| async move {
+                let outgoing = Self::new_id(is_wait_ide, id).run(OutgoingType::Dbus);
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    /// Blocking wait close IDE and stop engine
+    pub fn watch_ide(id: String) {
+        loop {
+            thread::sleep(Duration::from_secs(5));
+            let model = match SdkInstalledModel::search().into_iter().find(|e| e.id == id) {
+                Some(value) => value,
+                None => return,
+            };
+            if model.is_running {
+                continue;
+            }
+            if let Some(mut engine) = model.get_sdk_engine().ok().filter(|e| e.is_running) {
+                let _ = engine.stop();
+            }
+            return;
+        }
+    }
+
+    fn run(
+        model: SdkInstalledModel,
+        is_wait_ide: bool,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let mut engine = model.get_sdk_engine()?;
+        if !engine.is_running {
+            return Ok(StateMessageOutgoing::new_info(tr!("engine уже остановлен")));
+        }
+        if is_wait_ide && model.is_running {
+            let program = programs::get_aurora_bot()?;
+            exec::exec_spawn_args(&program, ["cli", "sdk", "engine", "--watch-ide", &model.id])?;
+            return Ok(StateMessageOutgoing::new_success(tr!(
+                "engine будет остановлен после закрытия IDE"
+            )));
+        }
+        StateMessageOutgoing::new_state(tr!("останавливаем engine...")).send(send_type);
+        engine.stop()?;
+        Ok(StateMessageOutgoing::new_success(tr!("engine успешно остановлен")))
+    }
+}
+
+impl TraitIncoming for SdkEngineStopIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Search
+        let key = SdkEngineStopIncoming::name();
+        let models: Vec<SdkInstalledModel> =
+            SdkInstalledModelSelect::search(&self.id, tr!("ищем Аврора SDK (MB2)"), &send_type)
+                .iter()
+                .filter(|e| e.build_type == SdkBuildType::MB2)
+                .cloned()
+                .collect();
+        // Select
+        match models.iter().count() {
+            1 => match Self::run(models.first().unwrap().clone(), self.is_wait_ide, &send_type) {
+                Ok(result) => result,
+                Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+            },
+            0 => StateMessageOutgoing::new_info(tr!("Аврора SDK не найдены")),
+            _ => match SdkInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Аврора SDK")),
+            },
+        }
+    }
+}
@@ -68,8 +68,13 @@ impl SdkIdeCloseIncoming {
     ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
         if model.is_running {
             StateMessageOutgoing::new_state(tr!("закрываем IDE")).send(send_type);
-            model.close_ide()?;
-            Ok(StateMessageOutgoing::new_success(tr!("IDE остановлено успешно")))
+            if model.close_ide()? {
+                Ok(StateMessageOutgoing::new_warning(tr!(
+                    "IDE не закрылось вовремя и было остановлено принудительно"
+                )))
+            } else {
+                Ok(StateMessageOutgoing::new_success(tr!("IDE остановлено успешно")))
+            }
         } else {
             Ok(StateMessageOutgoing::new_info(tr!("IDE уже закрыто")))
         }
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use dbus_crossroads::IfaceBuilder;
 use serde::Deserialize;
 use serde::Serialize;
@@ -11,10 +13,12 @@ use crate::feature::state_message::outgoing::StateMessageOutgoing;
 use crate::models::sdk_installed::model::SdkInstalledModel;
 use crate::service::dbus::server::IfaceData;
 use crate::tools::macros::tr;
+use crate::tools::utils;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SdkIdeOpenIncoming {
     id: Option<String>,
+    path: Option<PathBuf>,
 }
 
 impl SdkIdeOpenIncoming {
@@ -25,11 +29,28 @@ impl SdkIdeOpenIncoming {
     }
 
     pub fn new() -> Box<SdkIdeOpenIncoming> {
-        Box::new(Self { id: None })
+        Box::new(Self { id: None, path: None })
     }
 
     pub fn new_id(id: String) -> Box<SdkIdeOpenIncoming> {
-        Box::new(Self { id: Some(id) })
+        Box::new(Self {
+            id: Some(id),
+            path: None,
+        })
+    }
+
+    pub fn new_path(path: PathBuf) -> Box<SdkIdeOpenIncoming> {
+        Box::new(Self {
+            id: None,
+            path: Some(path),
+        })
+    }
+
+    pub fn new_path_id(path: PathBuf, id: String) -> Box<SdkIdeOpenIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            path: Some(path),
+        })
     }
 
     fn select(&self, id: String) -> SdkIdeOpenIncoming {
@@ -62,10 +83,46 @@ impl SdkIdeOpenIncoming {
         );
     }
 
+    pub fn dbus_method_run_path(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "Path"),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_path(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_path_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "PathById"),
+            ("path", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, id): (String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_path_id(path, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
     fn run(
         model: SdkInstalledModel,
+        path: &Option<PathBuf>,
         send_type: &OutgoingType,
     ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        if let Some(path) = path {
+            StateMessageOutgoing::new_state(tr!("открываем в IDE: {}", path.to_string_lossy())).send(send_type);
+            model.open_ide(path)?;
+            return Ok(StateMessageOutgoing::new_success(tr!("проект открыт в IDE")));
+        }
         if !model.is_running {
             StateMessageOutgoing::new_state(tr!("открываем IDE")).send(send_type);
             model.start_ide()?;
@@ -83,7 +140,7 @@ impl TraitIncoming for SdkIdeOpenIncoming {
         let models = SdkInstalledModelSelect::search(&self.id, tr!("ищем Аврора SDK"), &send_type);
         // Select
         match models.iter().count() {
-            1 => match Self::run(models.first().unwrap().clone(), &send_type) {
+            1 => match Self::run(models.first().unwrap().clone(), &self.path, &send_type) {
                 Ok(result) => result,
                 Err(_) => StateMessageOutgoing::new_error(tr!("не удалось открыть IDE")),
             },
@@ -0,0 +1,243 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use dbus_crossroads::IfaceBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::ClientMethodsKey;
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::feature::selector::selects::select_psdk_target::PsdkTargetModelSelect;
+use crate::feature::selector::selects::select_sdk_installed::SdkInstalledModelSelect;
+use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::models::psdk_tooling::model::PsdkAssistantNode;
+use crate::models::psdk_tooling::model::PsdkToolingModel;
+use crate::models::sdk_available::model::SdkBuildType;
+use crate::models::sdk_installed::model::SdkInstalledModel;
+use crate::models::sdk_installed_engine::model::SdkInstalledEngineModel;
+use crate::models::session::model::SessionModel;
+use crate::service::dbus::server::IfaceData;
+use crate::tools::macros::tr;
+use crate::tools::utils;
+
+use super::outgoing::SdkProjectBuildOutgoing;
+
+/// Platform SDK in engine
+const ENGINE_PSDK_DIR: &str = "/srv/mer/sdks/aurora_psdk";
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SdkProjectBuildIncoming {
+    id: Option<String>,
+    target_id: Option<String>,
+    path: PathBuf,
+}
+
+impl SdkProjectBuildIncoming {
+    pub fn name() -> String {
+        serde_variant::to_variant_name(&ClientMethodsKey::SdkProjectBuild)
+            .unwrap()
+            .to_string()
+    }
+
+    pub fn new(path: PathBuf) -> Box<SdkProjectBuildIncoming> {
+        Box::new(Self {
+            id: None,
+            target_id: None,
+            path,
+        })
+    }
+
+    pub fn new_id(path: PathBuf, id: String) -> Box<SdkProjectBuildIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: None,
+            path,
+        })
+    }
+
+    pub fn new_target_id(path: PathBuf, target_id: String, id: String) -> Box<SdkProjectBuildIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            target_id: Some(target_id),
+            path,
+        })
+    }
+
+    fn select(&self, id: String) -> SdkProjectBuildIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select
+    }
+
+    fn select_with_target(&self, id: String, target_id: String) -> SdkProjectBuildIncoming {
+        let mut select = self.clone();
+        select.id = Some(id);
+        select.target_id = Some(target_id);
+        select
+    }
+
+    pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            Self::name(),
+            ("path",),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path,): (String,)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new(path).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ById"),
+            ("path", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, id): (String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_id(path, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_target_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "TargetById"),
+            ("path", "target_id", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, target_id, id): (String, String, String)| async move {
+                let outgoing = match utils::path_to_absolute(&PathBuf::from(path)) {
+                    Some(path) => Self::new_target_id(path, target_id, id).run(OutgoingType::Dbus),
+                    None => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    /// Start engine if needed and get session
+    fn get_session(
+        model: &SdkInstalledModel,
+        send_type: &OutgoingType,
+    ) -> Result<(SdkInstalledEngineModel, SessionModel), Box<dyn std::error::Error>> {
+        let mut engine = model.get_sdk_engine()?;
+        if !engine.is_running {
+            StateMessageOutgoing::new_state(tr!("запускаем engine...")).send(send_type);
+            engine.start()?;
+        }
+        let session = engine.session()?;
+        Ok((engine, session))
+    }
+
+    /// Targets installed in engine
+    fn get_targets(session: &SessionModel) -> Result<Vec<PsdkTargetModel>, Box<dyn std::error::Error>> {
+        let lines = session.call("sdk-assistant list")?;
+        let (_, targets) = PsdkToolingModel::from_nodes(&PsdkAssistantNode::parse(&lines), ENGINE_PSDK_DIR);
+        Ok(targets)
+    }
+
+    fn run(
+        engine: SdkInstalledEngineModel,
+        session: &SessionModel,
+        target: PsdkTargetModel,
+        path: &Path,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let remote = engine.get_remote_path(path)?;
+        StateMessageOutgoing::new_state(tr!("сборка проекта для {}", target.full_name)).send(send_type);
+        let mut packages: Vec<String> = vec![];
+        let result = session.call_callback(
+            &format!(
+                "cd {} && mb2 -t {} build",
+                utils::shell_quote(&remote),
+                utils::shell_quote(&target.full_name)
+            ),
+            |line| {
+                // Wrote: /home/mersdk/share/project/RPMS/package.rpm
+                if let Some(rpm) = line.trim().strip_prefix("Wrote: ").filter(|e| e.ends_with(".rpm")) {
+                    packages.push(rpm.replacen(&remote, &path.to_string_lossy(), 1));
+                }
+                StateMessageOutgoing::new_state(line).send(send_type);
+            },
+        );
+        result?;
+        if packages.is_empty() {
+            Err(tr!("пакеты RPM не найдены"))?
+        }
+        Ok(SdkProjectBuildOutgoing::new(target.full_name, packages))
+    }
+}
+
+impl TraitIncoming for SdkProjectBuildIncoming {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        // Search
+        let key = SdkProjectBuildIncoming::name();
+        let models: Vec<SdkInstalledModel> =
+            SdkInstalledModelSelect::search(&self.id, tr!("ищем Аврора SDK (MB2)"), &send_type)
+                .iter()
+                .filter(|e| e.build_type == SdkBuildType::MB2)
+                .cloned()
+                .collect();
+        // Select
+        match models.iter().count() {
+            1 => {
+                let model = models.first().unwrap().clone();
+                let (engine, session) = match Self::get_session(&model, &send_type) {
+                    Ok(value) => value,
+                    Err(error) => {
+                        return StateMessageOutgoing::new_error(tr!("не удалось подключиться к engine: {}", error));
+                    }
+                };
+                let targets = match Self::get_targets(&session) {
+                    Ok(value) => value,
+                    Err(error) => {
+                        let _ = session.close();
+                        return StateMessageOutgoing::new_error(tr!("не удалось получить Platform Target: {}", error));
+                    }
+                };
+                let targets = PsdkTargetModelSelect::search(
+                    &self.target_id,
+                    tr!("получаем информацию о Platform Target"),
+                    &send_type,
+                    targets,
+                );
+                // Select target
+                let outgoing: Box<dyn TraitOutgoing> = match targets.iter().count() {
+                    1 => match Self::run(
+                        engine,
+                        &session,
+                        targets.first().unwrap().clone(),
+                        &self.path,
+                        &send_type,
+                    ) {
+                        Ok(result) => result,
+                        Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
+                    },
+                    0 => StateMessageOutgoing::new_info(tr!("Platform Target не найдены")),
+                    _ => match PsdkTargetModelSelect::select(key, &send_type, targets, |id| {
+                        self.select_with_target(model.id.clone(), id)
+                    }) {
+                        Ok(value) => Box::new(value),
+                        Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Platform Target")),
+                    },
+                };
+                let _ = session.close();
+                outgoing
+            }
+            0 => StateMessageOutgoing::new_info(tr!("Аврора SDK не найдены")),
+            _ => match SdkInstalledModelSelect::select(key, &send_type, models, |id| self.select(id)) {
+                Ok(value) => Box::new(value),
+                Err(_) => StateMessageOutgoing::new_error(tr!("не удалось получить Аврора SDK")),
+            },
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use colored::Colorize;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::feature::outgoing::DataOutgoing;
+use crate::feature::outgoing::TraitOutgoing;
+use crate::tools::macros::print_info;
+use crate::tools::macros::print_success;
+use crate::tools::macros::tr;
+
+use super::incoming::SdkProjectBuildIncoming;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SdkProjectBuildOutgoing {
+    target: String,
+    packages: Vec<String>,
+}
+
+impl SdkProjectBuildOutgoing {
+    pub fn new(target: String, packages: Vec<String>) -> Box<SdkProjectBuildOutgoing> {
+        Box::new(Self { target, packages })
+    }
+}
+
+impl TraitOutgoing for SdkProjectBuildOutgoing {
+    fn print(&self) {
+        let message = tr!("сборка для {} успешно завершена", self.target);
+        print_success!(message);
+        for item in &self.packages {
+            let message = format!("RPM: {}", item.bold().white());
+            print_info!(message);
+        }
+    }
+
+    fn to_json(&self) -> String {
+        DataOutgoing::serialize(SdkProjectBuildIncoming::name(), self.clone())
+    }
+}
@@ -9,15 +9,24 @@ use crate::feature::incoming::TraitIncoming;
 use crate::feature::outgoing::OutgoingType;
 use crate::feature::outgoing::TraitOutgoing;
 use crate::feature::state_message::outgoing::StateMessageOutgoing;
+use crate::models::sdk_installed::model::SdkInstalledModel;
 use crate::service::dbus::server::IfaceData;
 use crate::tools::format_utils;
+use crate::tools::format_utils::FormatConfig;
+use crate::tools::format_utils::FormatMode;
+use crate::tools::format_utils::FormatResult;
 use crate::tools::macros::tr;
+use crate::tools::programs;
 use crate::tools::utils;
 
+use super::outgoing::SdkProjectFormatOutgoing;
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SdkProjectFormatIncoming {
     id: Option<String>,
     path: PathBuf,
+    #[serde(default)]
+    mode: FormatMode,
 }
 
 impl SdkProjectFormatIncoming {
@@ -28,11 +37,23 @@ impl SdkProjectFormatIncoming {
     }
 
     pub fn new(path: PathBuf) -> Box<SdkProjectFormatIncoming> {
-        Box::new(Self { id: None, path })
+        Self::new_mode(path, FormatMode::Write)
     }
 
     pub fn new_id(path: PathBuf, id: String) -> Box<SdkProjectFormatIncoming> {
-        Box::new(Self { id: Some(id), path })
+        Self::new_mode_id(path, FormatMode::Write, id)
+    }
+
+    pub fn new_mode(path: PathBuf, mode: FormatMode) -> Box<SdkProjectFormatIncoming> {
+        Box::new(Self { id: None, path, mode })
+    }
+
+    pub fn new_mode_id(path: PathBuf, mode: FormatMode, id: String) -> Box<SdkProjectFormatIncoming> {
+        Box::new(Self {
+            id: Some(id),
+            path,
+            mode,
+        })
     }
 
     pub fn dbus_method_run(builder: &mut IfaceBuilder<IfaceData>) {
@@ -65,9 +86,84 @@ impl SdkProjectFormatIncoming {
         );
     }
 
-    fn run(path: &PathBuf) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+    pub fn dbus_method_run_mode(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "Mode"),
+            ("path", "mode"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, mode): (String, String)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    FormatMode::from_name(&mode),
+                ) {
+                    (Some(path), Some(mode)) => Self::new_mode(path, mode).run(OutgoingType::Dbus),
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                    (_, None) => StateMessageOutgoing::new_error(tr!("режим должен быть: write, check или diff")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    pub fn dbus_method_run_mode_by_id(builder: &mut IfaceBuilder<IfaceData>) {
+        builder.method_with_cr_async(
+            format!("{}{}", Self::name(), "ModeById"),
+            ("path", "mode", "id"),
+            ("result",),
+            move |mut ctx: dbus_crossroads::Context, _, (path, mode, id): (String, String, String)| async move {
+                let outgoing = match (
+                    utils::path_to_absolute(&PathBuf::from(path)),
+                    FormatMode::from_name(&mode),
+                ) {
+                    (Some(path), Some(mode)) => Self::new_mode_id(path, mode, id).run(OutgoingType::Dbus),
+                    (None, _) => StateMessageOutgoing::new_error(tr!("проверьте путь к проекту")),
+                    (_, None) => StateMessageOutgoing::new_error(tr!("режим должен быть: write, check или diff")),
+                };
+                ctx.reply(Ok((outgoing.to_json(),)))
+            },
+        );
+    }
+
+    /// qmlformat from Qt installed Aurora SDK, else from PATH
+    fn get_qmlformat(id: &Option<String>) -> Option<String> {
+        SdkInstalledModel::search()
+            .iter()
+            .filter(|e| id.is_none() || id.as_ref() == Some(&e.id))
+            .find_map(|e| e.get_qmlformat())
+            .or_else(|| programs::get_qmlformat().ok())
+    }
+
+    fn run(
+        path: &PathBuf,
+        mode: &FormatMode,
+        id: &Option<String>,
+        send_type: &OutgoingType,
+    ) -> Result<Box<dyn TraitOutgoing>, Box<dyn std::error::Error>> {
+        let config = FormatConfig::load(path)?;
         // Format
-        let result = format_utils::cpp_format(path)?;
+        let mut result = FormatResult::empty();
+        if config.cpp {
+            result.append(format_utils::cpp_format(path, mode, &config)?);
+        }
+        if config.qml {
+            match Self::get_qmlformat(id) {
+                Some(qmlformat) => result.append(format_utils::qml_format(path, &qmlformat, mode, &config)?),
+                None => {
+                    StateMessageOutgoing::new_warning(tr!("не найден qmlformat, файлы QML пропущены")).send(send_type)
+                }
+            }
+        }
+        if config.cmake {
+            match programs::get_cmake_format() {
+                Ok(_) => result.append(format_utils::cmake_format(path, mode, &config)?),
+                Err(_) => StateMessageOutgoing::new_warning(tr!("не найден cmake-format, файлы CMake пропущены"))
+                    .send(send_type),
+            }
+        }
+        // Check without write
+        if *mode != FormatMode::Write {
+            return Ok(SdkProjectFormatOutgoing::new(mode.clone(), result));
+        }
         // Result
         if result.count_formats == 0 {
             Ok(StateMessageOutgoing::new_info(tr!("проект не требует форматирования")))
@@ -91,8 +187,8 @@ impl SdkProjectFormatIncoming {
 }
 
 impl TraitIncoming for SdkProjectFormatIncoming {
-    fn run(&self, _: OutgoingType) -> Box<dyn TraitOutgoing> {
-        match Self::run(&self.path) {
+    fn run(&self, send_type: OutgoingType) -> Box<dyn TraitOutgoing> {
+        match Self::run(&self.path, &self.mode, &self.id, &send_type) {
             Ok(value) => value,
             Err(error) => StateMessageOutgoing::new_error(tr!("{}", error)),
         }
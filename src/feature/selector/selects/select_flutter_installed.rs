@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use serde::Serialize;
 
 use crate::feature::incoming::TraitIncoming;
@@ -7,6 +9,7 @@ use crate::feature::selector::outgoing::incoming::SelectorIncoming;
 use crate::feature::selector::outgoing::outgoing::SelectorOutgoing;
 use crate::feature::state_message::outgoing::StateMessageOutgoing;
 use crate::models::TraitModel;
+use crate::models::configuration::Config;
 use crate::models::flutter_installed::model::FlutterInstalledModel;
 use crate::tools::macros::tr;
 
@@ -40,4 +43,51 @@ impl FlutterInstalledModelSelect {
             FlutterInstalledModel::search()
         }
     }
+
+    /// Search with global default version Flutter SDK
+    pub fn search_default(id: &Option<String>, text: String, send_type: &OutgoingType) -> Vec<FlutterInstalledModel> {
+        let models = Self::search(id, text, send_type);
+        if id.is_some() {
+            return models;
+        }
+        let Some(version) = Config::load().flutter_default else {
+            return models;
+        };
+        let defaults = models
+            .iter()
+            .filter(|e| e.is_version(&version))
+            .cloned()
+            .collect::<Vec<FlutterInstalledModel>>();
+        if defaults.is_empty() { models } else { defaults }
+    }
+
+    /// Search with pin version project, without pin by global default version
+    pub fn search_project(
+        id: &Option<String>,
+        path: &Path,
+        text: String,
+        send_type: &OutgoingType,
+    ) -> Vec<FlutterInstalledModel> {
+        if id.is_some() {
+            return Self::search(id, text, send_type);
+        }
+        let Some((file, version)) = FlutterInstalledModel::read_pin(path) else {
+            return Self::search_default(id, text, send_type);
+        };
+        let models = Self::search(id, text, send_type)
+            .iter()
+            .filter(|e| e.is_version(&version))
+            .cloned()
+            .collect::<Vec<FlutterInstalledModel>>();
+        if models.is_empty() {
+            StateMessageOutgoing::new_warning(tr!(
+                "Flutter SDK {} из {} не установлен, выполните: aurora-bot cli flutter use {}",
+                version,
+                file.to_string_lossy(),
+                version
+            ))
+            .send(send_type);
+        }
+        models
+    }
 }
@@ -0,0 +1,39 @@
+use serde::Serialize;
+
+use crate::feature::incoming::TraitIncoming;
+use crate::feature::outgoing::OutgoingType;
+use crate::feature::selector::outgoing::incoming::SelectorIncoming;
+use crate::feature::selector::outgoing::outgoing::SelectorOutgoing;
+use crate::tools::macros::tr;
+
+/// Select url tarball from Platform SDK available model
+pub struct PsdkTarballSelect {}
+
+impl PsdkTarballSelect {
+    pub fn select<T: TraitIncoming + Serialize + Clone, F: Fn(String) -> T>(
+        key: String,
+        send_type: &OutgoingType,
+        urls: Vec<String>,
+        incoming: F,
+    ) -> Result<SelectorOutgoing<T>, Box<dyn std::error::Error>> {
+        Ok(SelectorOutgoing {
+            key,
+            send_type: send_type.clone(),
+            variants: urls
+                .iter()
+                .map(|e| SelectorIncoming {
+                    name: tr!("Tarball: {}", e.split("/").last().unwrap_or(e)),
+                    incoming: incoming(e.clone()),
+                })
+                .collect::<Vec<SelectorIncoming<T>>>(),
+        })
+    }
+
+    pub fn search(url: &Option<String>, urls: Vec<String>) -> Vec<String> {
+        if let Some(url) = url {
+            urls.iter().filter(|e| *e == url).cloned().collect()
+        } else {
+            urls
+        }
+    }
+}
@@ -25,7 +25,7 @@ impl PsdkTargetModelSelect {
             variants: models
                 .iter()
                 .map(|e| SelectorIncoming {
-                    name: tr!("Platform Target: {}", e.arch),
+                    name: tr!("Platform Target: {} ({})", e.full_name, e.tooling),
                     incoming: incoming(e.get_id()),
                 })
                 .collect::<Vec<SelectorIncoming<T>>>(),
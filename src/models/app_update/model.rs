@@ -0,0 +1,149 @@
+use std::cmp::Ordering;
+
+use colored::Colorize;
+use human_sort::compare;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::models::TraitModel;
+use crate::models::flutter_available::model::FlutterAvailableModel;
+use crate::models::flutter_installed::model::FlutterInstalledModel;
+use crate::models::psdk_available::model::PsdkAvailableModel;
+use crate::models::psdk_installed::model::PsdkInstalledModel;
+use crate::models::sdk_available::model::SdkAvailableModel;
+use crate::models::sdk_available::model::SdkBuildType;
+use crate::models::sdk_available::model::SdkInstallType;
+use crate::models::sdk_installed::model::SdkInstalledModel;
+use crate::tools::macros::print_info;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub enum AppUpdateType {
+    Sdk,
+    Psdk,
+    Flutter,
+}
+
+/// Available update installed tool
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct AppUpdateModel {
+    // Id available model for install by id
+    pub id: String,
+    pub update_type: AppUpdateType,
+    pub name: String,
+    pub installed: String,
+    pub available: String,
+    pub install: String,
+}
+
+impl TraitModel for AppUpdateModel {
+    fn get_id(&self) -> String {
+        self.id.clone()
+    }
+
+    fn get_key(&self) -> String {
+        format!("{}: {} -> {}", self.name, self.installed, self.available)
+    }
+
+    fn print(&self) {
+        let message = format!(
+            "{}: {} -> {}\nУстановить: {}",
+            self.name,
+            self.installed.bold().white(),
+            self.available.bold().green(),
+            self.install.bright_blue(),
+        );
+        print_info!(message);
+    }
+}
+
+impl AppUpdateModel {
+    /// Compare installed versions with latest available
+    pub fn search() -> Vec<AppUpdateModel> {
+        let mut models: Vec<AppUpdateModel> = vec![];
+        // Аврора SDK by build type
+        let sdks = SdkInstalledModel::search();
+        for build_type in [SdkBuildType::MB2, SdkBuildType::BT] {
+            let installed = Self::get_latest(
+                sdks.iter()
+                    .filter(|e| e.build_type == build_type)
+                    .map(|e| e.version.clone())
+                    .collect(),
+            );
+            let installed = match installed {
+                Some(value) => value,
+                None => continue,
+            };
+            let available = SdkAvailableModel::search_filter(|e| e.build_type == build_type);
+            let available = available
+                .iter()
+                .find(|e| e.install_type == SdkInstallType::Online)
+                .or(available.first());
+            if let Some(model) = available.filter(|e| Self::is_newer(&e.version_full, &installed)) {
+                models.push(AppUpdateModel {
+                    id: model.get_id(),
+                    update_type: AppUpdateType::Sdk,
+                    name: format!("Аврора SDK ({})", model.name_build_type()),
+                    installed,
+                    available: model.version_full.clone(),
+                    install: "aurora-bot cli sdk --install".to_string(),
+                });
+            }
+        }
+        // Platform SDK
+        let installed = Self::get_latest(
+            PsdkInstalledModel::search()
+                .iter()
+                .map(|e| e.version_id.clone())
+                .collect(),
+        );
+        if let Some(installed) = installed {
+            let available = PsdkAvailableModel::search();
+            if let Some(model) = available
+                .first()
+                .filter(|e| Self::is_newer(&e.version_full, &installed))
+            {
+                models.push(AppUpdateModel {
+                    id: model.get_id(),
+                    update_type: AppUpdateType::Psdk,
+                    name: "Platform SDK".to_string(),
+                    installed,
+                    available: model.version_full.clone(),
+                    install: "aurora-bot cli psdk --install".to_string(),
+                });
+            }
+        }
+        // Flutter SDK
+        let installed = Self::get_latest(
+            FlutterInstalledModel::search()
+                .iter()
+                .map(|e| e.flutter_version.clone())
+                .collect(),
+        );
+        if let Some(installed) = installed {
+            let available = FlutterAvailableModel::search();
+            // Release Aurora not in installed version: 3.27.4 and 3.27.4-1
+            if let Some(model) = available
+                .first()
+                .filter(|e| FlutterInstalledModel::compare_version(&e.version, &installed) == Ordering::Greater)
+            {
+                models.push(AppUpdateModel {
+                    id: model.get_id(),
+                    update_type: AppUpdateType::Flutter,
+                    name: "Flutter SDK".to_string(),
+                    installed,
+                    available: model.version.clone(),
+                    install: "aurora-bot cli flutter --install".to_string(),
+                });
+            }
+        }
+        models
+    }
+
+    fn get_latest(versions: Vec<String>) -> Option<String> {
+        versions.into_iter().max_by(|a, b| compare(a, b))
+    }
+
+    fn is_newer(available: &str, installed: &str) -> bool {
+        compare(available, installed) == Ordering::Greater
+    }
+}
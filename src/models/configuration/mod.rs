@@ -22,6 +22,8 @@ pub struct Config {
     pub device: Vec<DeviceConfig>,
     pub emulator: Vec<EmulatorConfig>,
     pub flutter: Vec<FlutterConfig>,
+    #[serde(default)]
+    pub flutter_default: Option<String>,
     pub psdk: Vec<PsdkConfig>,
     pub sdk: Vec<SdkConfig>,
 }
@@ -33,6 +35,7 @@ impl Config {
             device: vec![],
             emulator: vec![],
             flutter: vec![],
+            flutter_default: None,
             psdk: vec![],
             sdk: vec![],
         }
@@ -102,6 +105,15 @@ impl Config {
         config.save()
     }
 
+    pub fn save_flutter_default(version: Option<String>) -> bool {
+        let mut config = Config::load();
+        if config.flutter_default == version {
+            return false;
+        }
+        config.flutter_default = version;
+        config.save()
+    }
+
     pub fn save_psdk(list: Vec<PsdkConfig>) -> bool {
         let mut config = Config::load();
         if config.psdk == list {
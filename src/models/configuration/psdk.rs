@@ -1,6 +1,7 @@
 use crate::models::configuration::Config;
 use crate::models::psdk_installed::model::PsdkInstalledModel;
 use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::models::psdk_tooling::model::PsdkToolingModel;
 use crate::tools::macros::print_warning;
 use serde::Deserialize;
 use serde::Serialize;
@@ -14,6 +15,7 @@ pub struct PsdkConfig {
     pub version_id: String,
     pub build: u8,
     pub home_url: String,
+    pub toolings: Vec<PsdkToolingModel>,
     pub targets: Vec<PsdkTargetModel>,
 }
 
@@ -41,6 +43,7 @@ impl PsdkConfig {
                     version_id: e.version_id.clone(),
                     build: e.build,
                     home_url: e.home_url.clone(),
+                    toolings: e.toolings.clone(),
                     targets: e.targets.clone(),
                 })
                 .collect(),
@@ -60,6 +63,7 @@ impl PsdkConfig {
             version_id: self.version_id.clone(),
             build: self.build,
             home_url: self.home_url.clone(),
+            toolings: self.toolings.clone(),
             targets: self.targets.clone(),
         }
     }
@@ -1,11 +1,7 @@
-use std::error::Error;
-
 use crate::models::configuration::Config;
 use crate::models::sdk_available::model::SdkBuildType;
 use crate::models::sdk_installed::model::SdkInstalledModel;
-use crate::service::command::exec;
 use crate::tools::macros::print_warning;
-use crate::tools::utils;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -56,11 +52,6 @@ impl SdkConfig {
     }
 
     pub fn to_model(&self) -> SdkInstalledModel {
-        fn _get_is_running(sdk_dir: &String) -> Result<bool, Box<dyn Error>> {
-            let output = exec::exec_wait_args("ps", ["ax"])?;
-            let lines = utils::parse_output(output.stdout);
-            Ok(lines.iter().any(|e| e.contains(&format!("{}/bin/qtcreator", sdk_dir))))
-        }
         SdkInstalledModel {
             id: self.id.clone(),
             dir: self.dir.clone(),
@@ -69,7 +60,7 @@ impl SdkConfig {
             qt_creator_version: self.qt_creator_version.clone(),
             qt_version: self.qt_version.clone(),
             build_date: self.build_date.clone(),
-            is_running: _get_is_running(&self.dir).unwrap_or_else(|_| false),
+            is_running: !SdkInstalledModel::get_ide_pids(&self.dir).is_empty(),
             build_type: self.build_type.clone(),
         }
     }
@@ -0,0 +1,291 @@
+use colored::Colorize;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::models::TraitModel;
+use crate::models::device::model::DeviceModel;
+use crate::models::flutter_installed::model::FlutterInstalledModel;
+use crate::models::psdk_installed::model::PsdkInstalledModel;
+use crate::models::sdk_installed::model::SdkInstalledModel;
+use crate::service::command;
+use crate::service::dbus::server::ServerDbus;
+use crate::tools::constants;
+use crate::tools::macros::tr;
+use crate::tools::programs;
+use crate::tools::utils;
+
+/// Arch targets for build on devices and emulator
+const TARGET_ARCHS: [&str; 3] = ["aarch64", "armv7hl", "x86_64"];
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub enum DoctorCheckStatus {
+    Ok,
+    Warning,
+    Error,
+}
+
+/// Result check environment with hint for fix
+#[derive(Serialize, Deserialize, Clone)]
+pub struct DoctorCheckModel {
+    pub name: String,
+    pub status: DoctorCheckStatus,
+    pub message: String,
+    pub hint: Option<String>,
+}
+
+impl TraitModel for DoctorCheckModel {
+    fn get_id(&self) -> String {
+        format!("{:x}", md5::compute(self.name.as_bytes()))
+    }
+
+    fn get_key(&self) -> String {
+        self.name.clone()
+    }
+
+    fn print(&self) {
+        let mark = match self.status {
+            DoctorCheckStatus::Ok => "✔".green(),
+            DoctorCheckStatus::Warning => "!".yellow(),
+            DoctorCheckStatus::Error => "✘".red(),
+        };
+        println!("{} {}: {}", mark, self.name.bold().white(), self.message);
+        if let Some(hint) = &self.hint {
+            println!("  {}", hint.bright_blue());
+        }
+    }
+}
+
+impl DoctorCheckModel {
+    fn new_ok(name: String, message: String) -> DoctorCheckModel {
+        DoctorCheckModel {
+            name,
+            status: DoctorCheckStatus::Ok,
+            message,
+            hint: None,
+        }
+    }
+
+    fn new_fail(name: String, status: DoctorCheckStatus, message: String, hint: String) -> DoctorCheckModel {
+        DoctorCheckModel {
+            name,
+            status,
+            message,
+            hint: Some(hint),
+        }
+    }
+
+    fn check_program(
+        name: &str,
+        program: Result<String, Box<dyn std::error::Error>>,
+        status: DoctorCheckStatus,
+        hint: String,
+    ) -> DoctorCheckModel {
+        match program {
+            Ok(program) => Self::new_ok(name.to_string(), tr!("найден {}", program)),
+            Err(error) => Self::new_fail(name.to_string(), status, error.to_string(), hint),
+        }
+    }
+
+    /// Check all dependencies application
+    pub fn search() -> Vec<DoctorCheckModel> {
+        let mut models: Vec<DoctorCheckModel> = vec![];
+        models.append(&mut Self::search_programs());
+        models.append(&mut Self::search_psdk());
+        models.append(&mut Self::search_sdk());
+        models.append(&mut Self::search_devices());
+        models.append(&mut Self::search_service());
+        models
+    }
+
+    fn search_programs() -> Vec<DoctorCheckModel> {
+        let terminal: Result<String, Box<dyn std::error::Error>> = programs::get_kitty_terminal()
+            .or_else(|_| programs::get_gnome_terminal())
+            .map_err(|_| tr!("не найден Kitty или GNOME Terminal").into());
+        vec![
+            Self::check_program(
+                "sudo",
+                programs::get_sudo(),
+                DoctorCheckStatus::Error,
+                tr!("установите sudo, он необходим для работы с Platform SDK"),
+            ),
+            Self::check_program(
+                "tar",
+                programs::get_tar(),
+                DoctorCheckStatus::Error,
+                tr!("установите tar, он необходим для установки SDK"),
+            ),
+            Self::check_program(
+                "VirtualBox",
+                programs::get_vboxmanage(),
+                DoctorCheckStatus::Warning,
+                tr!("установите VirtualBox для работы с эмулятором"),
+            ),
+            Self::check_program(
+                tr!("Терминал").as_str(),
+                terminal,
+                DoctorCheckStatus::Warning,
+                tr!("установите Kitty или GNOME Terminal для открытия окружений в терминале"),
+            ),
+            Self::check_program(
+                "sshpass",
+                programs::get_sshpass(),
+                DoctorCheckStatus::Warning,
+                tr!("установите sshpass для работы с устройствами по паролю"),
+            ),
+            Self::check_program(
+                "clang-format",
+                programs::get_clang_format(),
+                DoctorCheckStatus::Warning,
+                tr!("установите clang-format для форматирования C++"),
+            ),
+            Self::check_program(
+                "cmake-format",
+                programs::get_cmake_format(),
+                DoctorCheckStatus::Warning,
+                tr!("установите cmake-format или gersemi для форматирования CMake"),
+            ),
+            Self::check_program(
+                "diff",
+                programs::get_diff(),
+                DoctorCheckStatus::Warning,
+                tr!("установите diff для просмотра изменений форматирования"),
+            ),
+            Self::check_program(
+                "xdg-open",
+                programs::get_xdg_open(),
+                DoctorCheckStatus::Warning,
+                tr!("установите xdg-utils для открытия файлов и директорий"),
+            ),
+        ]
+    }
+
+    fn search_psdk() -> Vec<DoctorCheckModel> {
+        let psdks = PsdkInstalledModel::search();
+        if psdks.is_empty() {
+            return vec![Self::new_fail(
+                "Platform SDK".to_string(),
+                DoctorCheckStatus::Warning,
+                tr!("не найден"),
+                tr!("установите Platform SDK: aurora-bot cli psdk --install"),
+            )];
+        }
+        let mut models: Vec<DoctorCheckModel> = vec![];
+        for psdk in psdks {
+            let name = tr!("Platform SDK {}", psdk.version_id);
+            // Access chroot without password: sudo -n
+            if command::psdk::is_sudoers_chroot_access(&psdk) {
+                models.push(Self::new_ok(name.clone(), tr!("доступ sudo есть")));
+            } else {
+                models.push(Self::new_fail(
+                    name.clone(),
+                    DoctorCheckStatus::Error,
+                    tr!("нет доступа sudo к {}", psdk.chroot),
+                    tr!("добавьте записи sudoers: aurora-bot svc --permissions"),
+                ));
+            }
+            // Targets by arch
+            for arch in TARGET_ARCHS {
+                let name = tr!("Platform SDK {} ({})", psdk.version_id, arch);
+                match psdk.targets.iter().find(|e| e.arch == arch) {
+                    Some(target) => models.push(Self::new_ok(name, tr!("найден Target {}", target.name))),
+                    None => models.push(Self::new_fail(
+                        name,
+                        DoctorCheckStatus::Warning,
+                        tr!("Target не найден"),
+                        tr!("установите Target: aurora-bot cli psdk target --install"),
+                    )),
+                }
+            }
+        }
+        models
+    }
+
+    fn search_sdk() -> Vec<DoctorCheckModel> {
+        let mut models: Vec<DoctorCheckModel> = vec![];
+        match SdkInstalledModel::search().first() {
+            Some(sdk) => models.push(Self::new_ok(tr!("Аврора SDK"), tr!("найден {}", sdk.version))),
+            None => models.push(Self::new_fail(
+                tr!("Аврора SDK"),
+                DoctorCheckStatus::Warning,
+                tr!("не найден"),
+                tr!("установите Аврора SDK: aurora-bot cli sdk --install"),
+            )),
+        }
+        match FlutterInstalledModel::search().first() {
+            Some(flutter) => models.push(Self::new_ok(
+                "Flutter SDK".to_string(),
+                tr!("найден {}", flutter.flutter_version),
+            )),
+            None => models.push(Self::new_fail(
+                "Flutter SDK".to_string(),
+                DoctorCheckStatus::Warning,
+                tr!("не найден"),
+                tr!("установите Flutter SDK: aurora-bot cli flutter --install"),
+            )),
+        }
+        models
+    }
+
+    fn search_devices() -> Vec<DoctorCheckModel> {
+        let devices = DeviceModel::search();
+        if devices.is_empty() {
+            let path = utils::get_file_save_path(constants::DEVICES_CONFIGURATION_FILE);
+            return vec![Self::new_fail(
+                tr!("Устройства"),
+                DoctorCheckStatus::Warning,
+                tr!("не найдены"),
+                tr!(
+                    "добавьте устройства в {} и выполните: aurora-bot svc sync --device",
+                    path.to_string_lossy()
+                ),
+            )];
+        }
+        // Config has devices available on sync, check connect now
+        devices
+            .iter()
+            .map(|device| {
+                let name = tr!("Устройство {}", device.host);
+                match device.session_user() {
+                    Ok(session) => Self::new_ok(name, tr!("доступно: {} {}", session.os_name, session.os_version)),
+                    Err(_) => Self::new_fail(
+                        name,
+                        DoctorCheckStatus::Error,
+                        tr!("недоступно"),
+                        tr!(
+                            "проверьте сеть, SSH и данные авторизации в {}",
+                            constants::DEVICES_CONFIGURATION_FILE
+                        ),
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    fn search_service() -> Vec<DoctorCheckModel> {
+        let mut models: Vec<DoctorCheckModel> = vec![];
+        if utils::get_file_save_path(constants::SESSION_FILE).exists() {
+            models.push(Self::new_ok(tr!("Авторизация"), tr!("сессия найдена")));
+        } else {
+            models.push(Self::new_fail(
+                tr!("Авторизация"),
+                DoctorCheckStatus::Warning,
+                tr!("сессия не найдена"),
+                tr!("авторизуйтесь: aurora-bot svc --auth <token>"),
+            ));
+        }
+        if ServerDbus::is_running() {
+            models.push(Self::new_ok(
+                "D-Bus".to_string(),
+                tr!("сервис {} запущен", constants::DBUS_NAME),
+            ));
+        } else {
+            models.push(Self::new_fail(
+                "D-Bus".to_string(),
+                DoctorCheckStatus::Warning,
+                tr!("сервис {} не запущен", constants::DBUS_NAME),
+                tr!("запустите сервис: aurora-bot svc --dbus"),
+            ));
+        }
+        models
+    }
+}
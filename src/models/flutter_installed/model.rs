@@ -1,14 +1,20 @@
 use colored::Colorize;
+use human_sort::compare;
 use human_sort::sort;
 
 use crate::models::TraitModel;
 use crate::models::configuration::flutter::FlutterConfig;
-use crate::service::command::exec;
+use crate::tools::constants;
 use crate::tools::macros::print_info;
+use crate::tools::macros::tr;
 use crate::tools::utils;
 use serde::Deserialize;
 use serde::Serialize;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct FlutterInstalledModel {
@@ -25,8 +31,68 @@ impl FlutterInstalledModel {
     pub fn get_id(flutter: &str) -> String {
         format!("{:x}", md5::compute(flutter.as_bytes()))
     }
+
+    /// Clear version from tag: aurora-3.27.4-1 -> 3.27.4-1
+    pub fn clear_version(version: &str) -> String {
+        version.trim().replace("aurora", "").trim_matches('-').to_string()
+    }
+
+    /// Split version to base and release Aurora: 3.27.4-1 -> (3.27.4, Some(1))
+    pub fn split_version(version: &str) -> (String, Option<String>) {
+        let version = Self::clear_version(version);
+        match version.split_once('-') {
+            Some((base, release)) => (base.to_string(), Some(release.to_string())),
+            None => (version, None),
+        }
+    }
+
+    /// Compare versions, release Aurora compared only if both have it: 3.27.4 == 3.27.4-1
+    pub fn compare_version(a: &str, b: &str) -> Ordering {
+        let (a_base, a_release) = Self::split_version(a);
+        let (b_base, b_release) = Self::split_version(b);
+        match compare(&a_base, &b_base) {
+            Ordering::Equal => match (a_release, b_release) {
+                (Some(a_release), Some(b_release)) => compare(&a_release, &b_release),
+                _ => Ordering::Equal,
+            },
+            ordering => ordering,
+        }
+    }
+
+    /// Version without release Aurora matches any release
+    pub fn is_version(&self, version: &str) -> bool {
+        Self::compare_version(&self.flutter_version, version) == Ordering::Equal
+    }
+
+    /// Search pin file in project directory and parents
+    pub fn read_pin(path: &Path) -> Option<(PathBuf, String)> {
+        for dir in path.ancestors() {
+            let file = dir.join(constants::FLUTTER_VERSION_FILE);
+            if !file.is_file() {
+                continue;
+            }
+            let version = Self::clear_version(&fs::read_to_string(&file).ok()?);
+            if version.is_empty() {
+                return None;
+            }
+            return Some((file, version));
+        }
+        None
+    }
+
+    /// Save pin file to project directory
+    pub fn write_pin(path: &Path, version: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let file = path.join(constants::FLUTTER_VERSION_FILE);
+        match fs::write(&file, format!("{}\n", Self::clear_version(version))) {
+            Ok(_) => Ok(file),
+            Err(_) => Err(tr!("не удалось записать файл"))?,
+        }
+    }
 }
 
+/// Dirs install Flutter SDK: aurora-bot, fvm, snap
+const FLUTTER_KNOWN_DIRS: [&str; 4] = [".local/opt", "fvm/versions", "snap/flutter/common", "/opt/flutter"];
+
 impl TraitModel for FlutterInstalledModel {
     fn get_id(&self) -> String {
         FlutterInstalledModel::get_id(&self.flutter)
@@ -65,63 +131,23 @@ impl FlutterInstalledModel {
         let mut models: Vec<FlutterInstalledModel> = vec![];
         let mut models_by_version: HashMap<String, FlutterInstalledModel> = HashMap::new();
         let mut versions: Vec<String> = vec![];
-        let flutters_path = utils::search_files_by_home("bin/flutter");
+        let flutters_path = utils::search_files_by_home("bin/flutter", &FLUTTER_KNOWN_DIRS);
         for flutter in flutters_path {
             let dir = flutter.clone().replace("/bin/flutter", "");
-            let _ = exec::exec_wait_args(&flutter, ["precache"])?;
-            let output = exec::exec_wait_args(&flutter, ["--version"])?;
-            let lines = utils::parse_output(output.stdout);
-            let flutter_version = match lines.get(0) {
-                Some(line) => line
-                    .split("•")
-                    .map(|e| e.trim())
-                    .collect::<Vec<&str>>()
-                    .get(0)
-                    .unwrap_or_else(|| &"")
-                    .split(" ")
-                    .collect::<Vec<&str>>()
-                    .get(1)
-                    .unwrap_or_else(|| &""),
-                None => "",
-            };
-            let dart_version = match lines.get(3) {
-                Some(line) => line
-                    .split("•")
-                    .map(|e| e.trim())
-                    .collect::<Vec<&str>>()
-                    .get(1)
-                    .unwrap_or_else(|| &"")
-                    .split(" ")
-                    .collect::<Vec<&str>>()
-                    .get(1)
-                    .unwrap_or_else(|| &""),
-                None => "",
-            };
-            let tools_version = match lines.get(3) {
-                Some(line) => line
-                    .split("•")
-                    .map(|e| e.trim())
-                    .collect::<Vec<&str>>()
-                    .get(2)
-                    .unwrap_or_else(|| &"")
-                    .split(" ")
-                    .collect::<Vec<&str>>()
-                    .get(1)
-                    .unwrap_or_else(|| &""),
-                None => "",
-            };
-            if flutter_version.is_empty() || dart_version.is_empty() || tools_version.is_empty() {
+            let Some(flutter_version) = Self::read_flutter_version(Path::new(&dir)) else {
                 continue;
-            }
+            };
+            let dart_version = Self::read_dart_version(Path::new(&dir)).unwrap_or_else(|| "undefined".to_string());
+            let tools_version = Self::read_tools_version(Path::new(&dir)).unwrap_or_else(|| "undefined".to_string());
             let id = FlutterInstalledModel::get_id(&flutter);
             let model = FlutterInstalledModel {
                 id: id.clone(),
                 dir,
                 flutter: flutter.clone(),
                 dart: flutter.replace("bin/flutter", "bin/dart").to_string(),
-                flutter_version: flutter_version.to_string(),
-                tools_version: tools_version.to_string(),
-                dart_version: dart_version.to_string(),
+                flutter_version: flutter_version.clone(),
+                tools_version,
+                dart_version,
             };
             let key = format!("{} ({})", flutter_version, id);
             models_by_version.insert(key.clone(), model);
@@ -137,4 +163,55 @@ impl FlutterInstalledModel {
         }
         Ok(models)
     }
+
+    /// Read json cache version flutter tool
+    fn read_version_json(dir: &Path) -> Option<serde_json::Value> {
+        let data = fs::read_to_string(dir.join("bin/cache/flutter.version.json")).ok()?;
+        serde_json::from_str::<serde_json::Value>(&data).ok()
+    }
+
+    /// Version from cache flutter tool, file version or name dir archive: flutter-3.27.4-1
+    fn read_flutter_version(dir: &Path) -> Option<String> {
+        let json = Self::read_version_json(dir);
+        let version = json
+            .as_ref()
+            .and_then(|e| e["flutterVersion"].as_str().or(e["frameworkVersion"].as_str()));
+        if let Some(version) = version {
+            return Some(version.to_string());
+        }
+        let version = fs::read_to_string(dir.join("version")).unwrap_or_default();
+        if !version.trim().is_empty() {
+            return Some(version.trim().to_string());
+        }
+        dir.ancestors()
+            .take(2)
+            .filter_map(|e| e.file_name())
+            .filter_map(|e| e.to_str())
+            .find_map(|e| e.strip_prefix("flutter-"))
+            .map(Self::clear_version)
+    }
+
+    fn read_dart_version(dir: &Path) -> Option<String> {
+        if let Ok(version) = fs::read_to_string(dir.join("bin/cache/dart-sdk/version")) {
+            return version.split_whitespace().next().map(|e| e.to_string());
+        }
+        Self::read_version_json(dir)?["dartSdkVersion"]
+            .as_str()?
+            .split_whitespace()
+            .next()
+            .map(|e| e.to_string())
+    }
+
+    fn read_tools_version(dir: &Path) -> Option<String> {
+        if let Some(version) = Self::read_version_json(dir)
+            .as_ref()
+            .and_then(|e| e["devToolsVersion"].as_str())
+        {
+            return Some(version.to_string());
+        }
+        let data = fs::read_to_string(dir.join("bin/cache/dart-sdk/bin/resources/devtools/version.json")).ok()?;
+        serde_json::from_str::<serde_json::Value>(&data).ok()?["version"]
+            .as_str()
+            .map(|e| e.to_string())
+    }
 }
@@ -1,6 +1,9 @@
 /// Application data models
 pub mod configuration;
 
+pub mod app_update {
+    pub mod model;
+}
 pub mod emulator {
     pub mod model;
 }
@@ -10,6 +13,9 @@ pub mod demo_app {
 pub mod device {
     pub mod model;
 }
+pub mod doctor_check {
+    pub mod model;
+}
 pub mod flutter_available {
     pub mod model;
 }
@@ -22,12 +28,18 @@ pub mod psdk_available {
 pub mod psdk_installed {
     pub mod model;
 }
+pub mod psdk_package_validate {
+    pub mod model;
+}
 pub mod psdk_target {
     pub mod model;
 }
 pub mod psdk_target_package {
     pub mod model;
 }
+pub mod psdk_tooling {
+    pub mod model;
+}
 pub mod pubspec {
     pub mod model;
 }
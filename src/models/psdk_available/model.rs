@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::Path;
 
 use crate::models::TraitModel;
 use crate::tools::macros::tr;
@@ -54,6 +55,33 @@ impl TraitModel for PsdkAvailableModel {
 }
 
 impl PsdkAvailableModel {
+    pub fn get_urls_targets(&self) -> Vec<String> {
+        self.urls
+            .iter()
+            .filter(|e| e.contains("Target") && !e.contains("md5sum"))
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_url_tooling(&self) -> Option<String> {
+        self.urls
+            .iter()
+            .find(|e| e.contains("Tooling") && !e.contains("md5sum"))
+            .cloned()
+    }
+
+    /// Version from name tarball: Aurora_OS-5.1.3.85-MB2-Aurora_SDK_Target-aarch64.tar.7z
+    pub fn get_tarball_version(path: &Path) -> Option<String> {
+        let name = path.file_name()?.to_string_lossy().to_string();
+        name.split("-").nth(1).map(|e| e.to_string())
+    }
+
+    /// Arch from name tarball: Aurora_OS-5.1.3.85-MB2-Aurora_SDK_Target-aarch64.tar.7z
+    pub fn get_tarball_arch(path: &Path) -> Option<String> {
+        let name = path.file_name()?.to_string_lossy().to_string();
+        name.split("-").last()?.split(".").next().map(|e| e.to_string())
+    }
+
     pub fn search() -> Vec<PsdkAvailableModel> {
         match Self::search_full() {
             Ok(value) => value,
@@ -4,6 +4,7 @@ use human_sort::sort;
 use crate::models::TraitModel;
 use crate::models::configuration::psdk::PsdkConfig;
 use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::models::psdk_tooling::model::PsdkToolingModel;
 use crate::tools::macros::print_info;
 use crate::tools::macros::print_warning;
 use crate::tools::utils;
@@ -12,6 +13,9 @@ use serde::Serialize;
 use std::collections::HashMap;
 use std::fs;
 
+/// Dirs install Platform SDK by default
+const PSDK_KNOWN_DIRS: [&str; 2] = ["AuroraPlatformSDK", "/opt/AuroraPlatformSDK"];
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct PsdkInstalledModel {
     pub id: String,
@@ -21,6 +25,7 @@ pub struct PsdkInstalledModel {
     pub version_id: String,
     pub build: u8,
     pub home_url: String,
+    pub toolings: Vec<PsdkToolingModel>,
     pub targets: Vec<PsdkTargetModel>,
 }
 
@@ -40,11 +45,20 @@ impl TraitModel for PsdkInstalledModel {
     }
 
     fn print(&self) {
-        let message = format!(
+        let mut message = format!(
             "Platform SDK: {}\nДиректория: {}",
             self.version_id.bold().white(),
             self.dir.to_string().bold().white(),
         );
+        for tooling in &self.toolings {
+            message = format!("{}\nTooling: {}", message, tooling.name.bold().white());
+            for target in self.targets.iter().filter(|e| e.tooling == tooling.name) {
+                message = format!("{}\n  Target: {}", message, target.full_name.white());
+                for snapshot in &target.snapshots {
+                    message = format!("{}\n    Snapshot: {}", message, snapshot.white());
+                }
+            }
+        }
         print_info!(message);
     }
 }
@@ -78,7 +92,7 @@ impl PsdkInstalledModel {
         let mut models: Vec<PsdkInstalledModel> = vec![];
         let mut models_by_version: HashMap<String, PsdkInstalledModel> = HashMap::new();
         let mut versions: Vec<String> = vec![];
-        let psdks_path = utils::search_files_by_home("aurora_psdk/sdk-chroot");
+        let psdks_path = utils::search_files_by_home("aurora_psdk/sdk-chroot", &PSDK_KNOWN_DIRS);
         for chroot in psdks_path {
             let psdk_dir = chroot.replace("/sdk-chroot", "");
             let psdk_release = psdk_dir.clone() + "/etc/aurora-release";
@@ -102,16 +116,16 @@ impl PsdkInstalledModel {
                 Ok(s) => s,
                 Err(_) => continue,
             };
-            let targets = if is_targets {
-                match PsdkTargetModel::search_full(chroot.clone(), psdk_dir.clone()) {
+            let (toolings, targets) = if is_targets {
+                match PsdkToolingModel::search_full(chroot.clone(), psdk_dir.clone()) {
                     Ok(value) => value,
                     Err(error) => {
                         print_warning!(error);
-                        vec![]
+                        (vec![], vec![])
                     }
                 }
             } else {
-                vec![]
+                (vec![], vec![])
             };
             let id = PsdkInstalledModel::get_id(&chroot);
             let model = PsdkInstalledModel {
@@ -122,6 +136,7 @@ impl PsdkInstalledModel {
                 version,
                 build,
                 home_url,
+                toolings,
                 targets,
             };
             let key = format!("{} ({})", version_id, id);
@@ -0,0 +1,103 @@
+use colored::Colorize;
+use regex::Regex;
+
+use crate::models::TraitModel;
+use crate::tools::macros::print_error;
+use crate::tools::macros::print_info;
+use crate::tools::macros::print_success;
+use crate::tools::macros::print_warning;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Group result rpm-validator by check
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PsdkPackageValidateModel {
+    pub id: String,
+    pub check: String,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+    pub info: Vec<String>,
+}
+
+impl PsdkPackageValidateModel {
+    pub fn get_id(key: &str) -> String {
+        format!("{:x}", md5::compute(key.as_bytes()))
+    }
+
+    fn new(check: &str) -> PsdkPackageValidateModel {
+        PsdkPackageValidateModel {
+            id: PsdkPackageValidateModel::get_id(check),
+            check: check.to_string(),
+            errors: vec![],
+            warnings: vec![],
+            info: vec![],
+        }
+    }
+}
+
+impl TraitModel for PsdkPackageValidateModel {
+    fn get_id(&self) -> String {
+        PsdkPackageValidateModel::get_id(&self.check)
+    }
+
+    fn get_key(&self) -> String {
+        self.check.clone()
+    }
+
+    fn print(&self) {
+        let message = format!("Check: {}", self.check.bold().white());
+        if self.errors.is_empty() {
+            print_success!(message);
+        } else {
+            print_error!(message);
+        }
+        for item in &self.errors {
+            print_error!(item);
+        }
+        for item in &self.warnings {
+            print_warning!(item);
+        }
+        for item in &self.info {
+            print_info!(item);
+        }
+    }
+}
+
+impl PsdkPackageValidateModel {
+    /// Parse output rpm-validator: `ERROR [Check] message`
+    pub fn parse(lines: &[String]) -> Vec<PsdkPackageValidateModel> {
+        let re_line =
+            Regex::new(r"^\s*(ERROR|WARNING|WARN|INFO|NOTICE)\s*:?\s*(?:\[([^\]]+)\])?\s*:?\s*(.*)$").unwrap();
+        // Section: `=== Name ===`, `--- Name` or `Name:`
+        let re_section = Regex::new(
+            r"^\s*(?:(?:={2,}|-{3,})\s*([A-Za-z][\w .\-/]*?)\s*(?:={2,}|-{3,})?|([A-Za-z][\w .\-/]*?)\s*:)\s*$",
+        )
+        .unwrap();
+        let mut result: Vec<PsdkPackageValidateModel> = vec![];
+        let mut section = "General".to_string();
+        for line in lines {
+            if let Some(caps) = re_line.captures(line) {
+                let check = caps.get(2).map(|e| e.as_str().trim()).unwrap_or(&section).to_string();
+                let message = caps.get(3).map(|e| e.as_str().trim()).unwrap_or_default().to_string();
+                if message.is_empty() {
+                    continue;
+                }
+                let index = match result.iter().position(|e| e.check == check) {
+                    Some(index) => index,
+                    None => {
+                        result.push(PsdkPackageValidateModel::new(&check));
+                        result.len() - 1
+                    }
+                };
+                match &caps[1] {
+                    "ERROR" => result[index].errors.push(message),
+                    "WARNING" | "WARN" => result[index].warnings.push(message),
+                    _ => result[index].info.push(message),
+                }
+            } else if let Some(name) = re_section.captures(line).and_then(|e| e.get(1).or(e.get(2))) {
+                section = name.as_str().trim().to_string();
+            }
+        }
+        result
+    }
+}
@@ -1,7 +1,7 @@
 use colored::Colorize;
 
 use crate::models::TraitModel;
-use crate::service::command;
+use crate::models::psdk_tooling::model::PsdkAssistantNode;
 use crate::tools::macros::print_info;
 use crate::tools::utils;
 use serde::Deserialize;
@@ -14,6 +14,8 @@ pub struct PsdkTargetModel {
     pub name: String,
     pub full_name: String,
     pub arch: String,
+    pub tooling: String,
+    pub snapshots: Vec<String>,
 }
 
 impl PsdkTargetModel {
@@ -32,53 +34,41 @@ impl TraitModel for PsdkTargetModel {
     }
 
     fn print(&self) {
-        let message = format!(
-            "Platform Target: {}\nАрхитектура: {}",
+        let mut message = format!(
+            "Platform Target: {}\nАрхитектура: {}\nTooling: {}",
             self.name.bold().white(),
             self.arch.to_string().bold().white(),
+            self.tooling.bold().white(),
         );
+        if !self.snapshots.is_empty() {
+            message = format!("{}\nSnapshots: {}", message, self.snapshots.join(", ").bold().white());
+        }
         print_info!(message);
     }
 }
 
 impl PsdkTargetModel {
-    pub fn search_full(chroot: String, dir: String) -> Result<Vec<PsdkTargetModel>, Box<dyn std::error::Error>> {
-        let mut models: Vec<PsdkTargetModel> = vec![];
-        let mut targets: Vec<String> = vec![];
-        let lines = command::psdk::psdk_targets_exec(&chroot)?;
-        match utils::config_get_string(&lines, "aarch64", "─") {
-            Ok(value) => targets.push(value),
-            Err(_) => {}
-        };
-        match utils::config_get_string(&lines, "armv7hl", "─") {
-            Ok(value) => targets.push(value),
-            Err(_) => {}
-        };
-        match utils::config_get_string(&lines, "x86_64", "─") {
-            Ok(value) => targets.push(value),
-            Err(_) => {}
-        };
-        match utils::config_get_string(&lines, "i486", "─") {
-            Ok(value) => targets.push(value),
-            Err(_) => {}
-        };
-        for full_name in &targets {
-            let dir = format!("{dir}/targets/{full_name}").replace("/sdks/aurora_psdk", "");
-            let arch = match full_name.split("-").last() {
-                Some(value) => value,
-                None => continue,
-            };
-            if arch != "aarch64" && arch != "armv7hl" && arch != "x86_64" && arch != "i486" {
-                continue;
-            }
-            models.push(PsdkTargetModel {
-                id: PsdkTargetModel::get_id(&dir),
-                dir,
-                name: full_name.replace(&format!("-{arch}"), ""),
-                full_name: full_name.clone(),
-                arch: arch.to_string(),
-            });
+    pub fn from_node(node: &PsdkAssistantNode, tooling: &str, root: &str) -> Option<PsdkTargetModel> {
+        let full_name = node.name.clone();
+        let arch = PsdkTargetModel::get_arch(&full_name)?;
+        let dir = format!("{root}/targets/{full_name}");
+        Some(PsdkTargetModel {
+            id: PsdkTargetModel::get_id(&dir),
+            dir,
+            name: full_name.replace(&format!("-{arch}"), ""),
+            full_name,
+            arch,
+            tooling: tooling.to_string(),
+            snapshots: node.children.iter().map(|e| e.name.clone()).collect(),
+        })
+    }
+
+    /// Arch from name target or snapshot: AuroraOS-5.1.1.60-base-aarch64.default
+    pub fn get_arch(name: &str) -> Option<String> {
+        let arch = name.split("-").last()?.split(".").next()?;
+        if arch != "aarch64" && arch != "armv7hl" && arch != "x86_64" && arch != "i486" {
+            return None;
         }
-        Ok(models)
+        Some(arch.to_string())
     }
 }
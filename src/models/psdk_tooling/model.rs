@@ -0,0 +1,118 @@
+use colored::Colorize;
+
+use crate::models::TraitModel;
+use crate::models::psdk_target::model::PsdkTargetModel;
+use crate::service::command;
+use crate::tools::macros::print_info;
+use crate::tools::utils;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PsdkToolingModel {
+    pub id: String,
+    pub dir: String,
+    pub name: String,
+}
+
+impl PsdkToolingModel {
+    pub fn get_id(key: &str) -> String {
+        format!("{:x}", md5::compute(key.as_bytes()))
+    }
+}
+
+impl TraitModel for PsdkToolingModel {
+    fn get_id(&self) -> String {
+        PsdkToolingModel::get_id(&self.dir)
+    }
+
+    fn get_key(&self) -> String {
+        utils::key_from_path(&self.dir)
+    }
+
+    fn print(&self) {
+        let message = format!("Platform Tooling: {}", self.name.bold().white());
+        print_info!(message);
+    }
+}
+
+/// Node tree output `sdk-assistant list`
+#[derive(Clone, Debug)]
+pub struct PsdkAssistantNode {
+    pub name: String,
+    pub children: Vec<PsdkAssistantNode>,
+}
+
+impl PsdkAssistantNode {
+    /// Parse tree: toolings -> targets -> snapshots
+    pub fn parse(lines: &[String]) -> Vec<PsdkAssistantNode> {
+        let mut roots: Vec<PsdkAssistantNode> = vec![];
+        // Stack open nodes by depth
+        let mut stack: Vec<PsdkAssistantNode> = vec![];
+        for line in lines {
+            let prefix = line
+                .chars()
+                .take_while(|c| matches!(c, '│' | '├' | '└' | '─' | ' '))
+                .count();
+            let name = match line.chars().skip(prefix).collect::<String>().split_whitespace().next() {
+                Some(value) => value.to_string(),
+                None => continue,
+            };
+            if !name.chars().next().is_some_and(|c| c.is_alphanumeric()) || name.ends_with(':') {
+                continue;
+            }
+            // Each level of tree take 4 chars: "├── ", "│   "
+            let depth = prefix.div_ceil(4);
+            if depth > stack.len() {
+                continue;
+            }
+            while stack.len() > depth {
+                Self::close(&mut stack, &mut roots);
+            }
+            stack.push(PsdkAssistantNode { name, children: vec![] });
+        }
+        while !stack.is_empty() {
+            Self::close(&mut stack, &mut roots);
+        }
+        roots
+    }
+
+    fn close(stack: &mut Vec<PsdkAssistantNode>, roots: &mut Vec<PsdkAssistantNode>) {
+        if let Some(node) = stack.pop() {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(node),
+                None => roots.push(node),
+            }
+        }
+    }
+}
+
+impl PsdkToolingModel {
+    pub fn search_full(
+        chroot: String,
+        dir: String,
+    ) -> Result<(Vec<PsdkToolingModel>, Vec<PsdkTargetModel>), Box<dyn std::error::Error>> {
+        let lines = command::psdk::psdk_targets_exec(&chroot)?;
+        Ok(Self::from_nodes(&PsdkAssistantNode::parse(&lines), &dir))
+    }
+
+    pub fn from_nodes(nodes: &[PsdkAssistantNode], dir: &str) -> (Vec<PsdkToolingModel>, Vec<PsdkTargetModel>) {
+        let root = dir.replace("/sdks/aurora_psdk", "");
+        let mut toolings: Vec<PsdkToolingModel> = vec![];
+        let mut targets: Vec<PsdkTargetModel> = vec![];
+        for node in nodes {
+            let dir = format!("{root}/toolings/{}", node.name);
+            toolings.push(PsdkToolingModel {
+                id: PsdkToolingModel::get_id(&dir),
+                dir,
+                name: node.name.clone(),
+            });
+            for child in &node.children {
+                if let Some(target) = PsdkTargetModel::from_node(child, &node.name, &root) {
+                    targets.push(target);
+                }
+            }
+        }
+        (toolings, targets)
+    }
+}
@@ -1,13 +1,52 @@
+use std::collections::HashMap;
+use std::env;
 use std::fs;
 use std::path::PathBuf;
 
+use serde::Serialize;
+use serde_json::Value;
+use yaml_rust::Yaml;
 use yaml_rust::YamlLoader;
 
 use crate::service::requests::client::ClientRequest;
+use crate::service::responses::gitlab_tags::GitlabTagsResponse;
+use crate::tools::license_utils;
+use crate::tools::license_utils::LicenseConfig;
+use crate::tools::license_utils::LicenseStatus;
 use crate::tools::macros::tr;
 use crate::tools::utils;
 
-#[derive(Clone, Debug)]
+/// Platforms implementations plugins not used on Aurora OS
+const OTHER_PLATFORMS: [&str; 8] = [
+    "android",
+    "ios",
+    "linux",
+    "macos",
+    "windows",
+    "web",
+    "foundation",
+    "darwin",
+];
+
+/// Compatibility plugin with Aurora OS
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub enum PubspecAurora {
+    Supported,
+    Port,
+    Independent,
+}
+
+impl PubspecAurora {
+    pub fn to_name(&self) -> String {
+        match self {
+            PubspecAurora::Supported => tr!("поддерживается"),
+            PubspecAurora::Port => tr!("требуется портирование"),
+            PubspecAurora::Independent => tr!("не зависит от платформы"),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
 pub struct PubspecModel {
     pub name: String,
     pub version: String,
@@ -15,9 +54,25 @@ pub struct PubspecModel {
     pub repository: Option<String>,
     pub pub_dev: Option<String>,
     pub is_plugin: bool,
+    pub is_native: bool,
+    pub platforms: Vec<String>,
+    pub aurora: Option<PubspecAurora>,
+    pub version_lock: Option<String>,
+    pub source: Option<String>,
+    pub dependencies: Vec<String>,
+    pub license: Option<String>,
+    pub license_status: Option<LicenseStatus>,
     pub level: i32,
 }
 
+/// Package from pubspec.lock, path to sources for git and path dependencies
+#[derive(Clone, Debug)]
+pub struct PubspecLockModel {
+    pub version: String,
+    pub source: String,
+    pub path: Option<PathBuf>,
+}
+
 impl PubspecModel {
     pub fn parse_model(path_pubspec: &PathBuf) -> Result<PubspecModel, Box<dyn std::error::Error>> {
         // Load file
@@ -58,6 +113,8 @@ impl PubspecModel {
             Some(value) => value.iter().any(|e| e.0.as_str() == Some("plugin")),
             None => false,
         };
+        let (platforms, is_native) =
+            Self::get_platforms(is_plugin, &Self::yaml_to_json(&doc["flutter"]["plugin"]["platforms"]));
         Ok(PubspecModel {
             name: name.clone(),
             version,
@@ -65,18 +122,29 @@ impl PubspecModel {
             repository,
             pub_dev: utils::check_url(format!("https://pub.dev/packages/{}", name)),
             is_plugin,
+            is_native,
+            platforms,
+            aurora: None,
+            version_lock: None,
+            source: None,
+            dependencies: names_dependencies,
+            license: path_pubspec.parent().and_then(license_utils::license_from_dir),
+            license_status: None,
             level: 0,
         })
     }
 
-    pub fn search_dependencies<T: Fn(i32) + Send + Copy + Sync + 'static>(
-        path_pubspec: &PathBuf,
-        state: T,
-    ) -> Result<Vec<PubspecModel>, Box<dyn std::error::Error>> {
+    /// Version from pubspec.lock older than latest
+    pub fn is_outdated(&self) -> bool {
+        self.source.as_deref() == Some("hosted") && self.version_lock.as_ref().is_some_and(|e| *e != self.version)
+    }
+
+    /// Names dependencies from pubspec.yaml
+    pub fn parse_dependencies(path_pubspec: &PathBuf) -> Result<Vec<String>, Box<dyn std::error::Error>> {
         // Load file
         let content = fs::read_to_string(path_pubspec)?;
         // Parse yaml
-        let docs = YamlLoader::load_from_str(&content).unwrap();
+        let docs = YamlLoader::load_from_str(&content)?;
         let doc = &docs[0];
         // Get data
         let mut names_dependencies: Vec<String> = vec![];
@@ -85,7 +153,203 @@ impl PubspecModel {
                 names_dependencies.push(key.as_str().unwrap().to_string());
             }
         };
+        Ok(names_dependencies)
+    }
+
+    /// Names hosted dependencies and dev_dependencies from pubspec.yaml, without sdk, git and path
+    pub fn parse_hosted_dependencies(path_pubspec: &PathBuf) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path_pubspec)?;
+        let docs = YamlLoader::load_from_str(&content)?;
+        let doc = &docs[0];
+        let mut names: Vec<String> = vec![];
+        for key in ["dependencies", "dev_dependencies"] {
+            let Some(dependencies) = doc[key].as_hash() else {
+                continue;
+            };
+            for (name, value) in dependencies.iter() {
+                let Some(name) = name.as_str() else {
+                    continue;
+                };
+                if ["sdk", "git", "path"].iter().any(|e| !value[*e].is_badvalue()) {
+                    continue;
+                }
+                if !names.iter().any(|e| e == name) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        Ok(names)
+    }
+
+    /// Parse pubspec.lock near pubspec.yaml if exist
+    pub fn parse_lock(path_pubspec: &PathBuf) -> Result<HashMap<String, PubspecLockModel>, Box<dyn std::error::Error>> {
+        let mut packages: HashMap<String, PubspecLockModel> = HashMap::new();
+        let path_lock = path_pubspec.with_file_name("pubspec.lock");
+        if !path_lock.exists() {
+            return Ok(packages);
+        }
+        let content = fs::read_to_string(&path_lock)?;
+        let docs = YamlLoader::load_from_str(&content)?;
+        let Some(values) = docs[0]["packages"].as_hash() else {
+            Err(tr!("не удалось прочитать pubspec.lock"))?
+        };
+        let path_project = path_pubspec.parent().map(|e| e.to_path_buf()).unwrap_or_default();
+        let path_cache = match env::var("PUB_CACHE") {
+            Ok(value) => PathBuf::from(value),
+            Err(_) => utils::get_home_folder_path().join(".pub-cache"),
+        };
+        for (key, value) in values.iter() {
+            let Some(name) = key.as_str() else {
+                continue;
+            };
+            let source = value["source"].as_str().unwrap_or_default().to_string();
+            let description = &value["description"];
+            let path = match source.as_str() {
+                // description: {path: "../plugin", relative: true}
+                "path" => description["path"].as_str().map(|e| path_project.join(e)),
+                // ~/.pub-cache/git/repository-resolved-ref/path
+                "git" => match (description["url"].as_str(), description["resolved-ref"].as_str()) {
+                    (Some(url), Some(resolved)) => {
+                        let repository = url
+                            .trim_end_matches('/')
+                            .rsplit(['/', ':'])
+                            .next()
+                            .unwrap_or_default()
+                            .trim_end_matches(".git");
+                        Some(
+                            path_cache
+                                .join("git")
+                                .join(format!("{repository}-{resolved}"))
+                                .join(description["path"].as_str().unwrap_or(".")),
+                        )
+                    }
+                    _ => None,
+                },
+                _ => None,
+            };
+            packages.insert(
+                name.to_string(),
+                PubspecLockModel {
+                    version: value["version"].as_str().unwrap_or_default().to_string(),
+                    source,
+                    path: path.filter(|e| e.join("pubspec.yaml").exists()),
+                },
+            );
+        }
+        Ok(packages)
+    }
+
+    pub fn search_dependencies<T: Fn(i32) + Send + Copy + Sync + 'static>(
+        path_pubspec: &PathBuf,
+        state: T,
+    ) -> Result<Vec<PubspecModel>, Box<dyn std::error::Error>> {
+        let names_dependencies = PubspecModel::parse_dependencies(path_pubspec)?;
+        let locks = PubspecModel::parse_lock(path_pubspec)?;
         // Result
-        Ok(ClientRequest::new(None).get_dart_packages(&names_dependencies, state)?)
+        Ok(ClientRequest::new(None).get_dart_packages(&names_dependencies, &locks, state)?)
+    }
+
+    /// Platforms plugin and has native code: pluginClass, default_package, ffiPlugin
+    pub fn get_platforms(is_plugin: bool, platforms: &Value) -> (Vec<String>, bool) {
+        let mut result: Vec<String> = vec![];
+        let mut is_native = false;
+        if let Some(values) = platforms.as_object() {
+            for (key, value) in values.iter() {
+                result.push(key.clone());
+                is_native = is_native
+                    || value.get("pluginClass").is_some()
+                    || value.get("default_package").is_some()
+                    || value["ffiPlugin"].as_bool() == Some(true);
+            }
+        } else if is_plugin {
+            // Old format plugin: androidPackage, iosPrefix, pluginClass
+            is_native = true;
+        }
+        (result, is_native)
+    }
+
+    fn yaml_to_json(value: &Yaml) -> Value {
+        match value {
+            Yaml::Hash(values) => Value::Object(
+                values
+                    .iter()
+                    .filter_map(|(key, value)| Some((key.as_str()?.to_string(), Self::yaml_to_json(value))))
+                    .collect(),
+            ),
+            Yaml::Array(values) => Value::Array(values.iter().map(Self::yaml_to_json).collect()),
+            Yaml::String(value) => Value::String(value.clone()),
+            Yaml::Boolean(value) => Value::Bool(*value),
+            Yaml::Integer(value) => Value::from(*value),
+            _ => Value::Null,
+        }
+    }
+
+    /// Mark plugins compatibility with Aurora OS by platforms and tags repos OMP
+    pub fn check_aurora(dependencies: &mut [PubspecModel], tags: &[GitlabTagsResponse]) {
+        // Tag: name_aurora-1.0.0
+        let ports: Vec<String> = tags
+            .iter()
+            .filter_map(|e| {
+                e.name
+                    .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                    .next()
+                    .map(|e| e.to_string())
+            })
+            .collect();
+        let names: Vec<String> = dependencies.iter().map(|e| e.name.clone()).collect();
+        for dependency in dependencies.iter_mut().filter(|e| e.is_plugin) {
+            let name_aurora = format!("{}_aurora", dependency.name);
+            let is_other_platform = OTHER_PLATFORMS
+                .iter()
+                .any(|e| dependency.name.ends_with(&format!("_{e}")));
+            dependency.aurora = if dependency.platforms.iter().any(|e| e == "aurora")
+                || ports.contains(&dependency.name)
+                || ports.contains(&name_aurora)
+                || names.contains(&name_aurora)
+            {
+                Some(PubspecAurora::Supported)
+            } else if !dependency.is_native || is_other_platform || dependency.name.ends_with("_platform_interface") {
+                Some(PubspecAurora::Independent)
+            } else {
+                Some(PubspecAurora::Port)
+            };
+        }
+    }
+
+    /// Check licenses packages by allow-list
+    pub fn check_licenses(dependencies: &mut [PubspecModel], config: &LicenseConfig) {
+        for dependency in dependencies.iter_mut() {
+            // Request license failed, not check
+            if dependency.is_license_failed() {
+                continue;
+            }
+            dependency.license_status = Some(config.check(&dependency.name, dependency.license.as_ref()));
+        }
+    }
+
+    /// License not allowed: copyleft, unknown or not in allow-list
+    pub fn is_license_violation(&self) -> bool {
+        self.license_status
+            .as_ref()
+            .is_some_and(|e| *e != LicenseStatus::Allowed && *e != LicenseStatus::Failed)
+    }
+
+    /// License not received from pub.dev
+    pub fn is_license_failed(&self) -> bool {
+        self.license_status.as_ref() == Some(&LicenseStatus::Failed)
+    }
+
+    /// Porting effort by count plugins need port
+    pub fn get_aurora_effort(dependencies: &[PubspecModel]) -> String {
+        match dependencies
+            .iter()
+            .filter(|e| e.aurora == Some(PubspecAurora::Port))
+            .count()
+        {
+            0 => tr!("не требуется"),
+            1..=2 => tr!("низкая"),
+            3..=5 => tr!("средняя"),
+            _ => tr!("высокая"),
+        }
     }
 }
@@ -6,11 +6,23 @@ use crate::models::sdk_available::model::SdkBuildType;
 use crate::models::sdk_installed_engine::model::SdkInstalledEngineModel;
 use crate::service::command::exec;
 use crate::tools::macros::print_info;
+use crate::tools::macros::tr;
 use crate::tools::utils;
+use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
 use std::error::Error;
 use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+use walkdir::WalkDir;
+
+/// Dirs install Aurora SDK by default
+const SDK_KNOWN_DIRS: [&str; 2] = ["AuroraOS", "/opt/AuroraOS"];
+
+/// Seconds wait IDE close after SIGTERM
+const IDE_CLOSE_TIMEOUT: u64 = 30;
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SdkInstalledModel {
@@ -65,17 +77,49 @@ impl SdkInstalledModel {
         Ok(())
     }
 
-    pub fn close_ide(&self) -> Result<(), Box<dyn std::error::Error>> {
-        // Get pid
-        let output = exec::exec_wait_args("ps", ["ax"])?;
-        let lines = utils::parse_output(output.stdout);
-        let pid = utils::config_get_string_index(&lines, &format!("{}/bin/qtcreator", self.dir), " ", 0)?;
-        // Close
-        let _ = exec::exec_wait_args("kill", ["-9", &pid])?;
-        // Success
+    /// Open project or file in IDE, running IDE open it in current window
+    pub fn open_ide(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        exec::exec_detach_args(&format!("{}/bin/qtcreator.sh", self.dir), [path], 3)?;
         Ok(())
     }
 
+    /// Close IDE: SIGTERM, after timeout SIGKILL. Return true if closed by force
+    pub fn close_ide(&self) -> Result<bool, Box<dyn std::error::Error>> {
+        let pids = SdkInstalledModel::get_ide_pids(&self.dir);
+        if pids.is_empty() {
+            Err(tr!("процесс IDE не найден"))?
+        }
+        for pid in &pids {
+            let _ = exec::exec_wait_args("kill", ["-TERM", &pid.to_string()])?;
+        }
+        for _ in 0..IDE_CLOSE_TIMEOUT {
+            if !pids.iter().any(|e| utils::is_pid_running(*e)) {
+                return Ok(false);
+            }
+            thread::sleep(Duration::from_secs(1));
+        }
+        for pid in pids.iter().filter(|e| utils::is_pid_running(**e)) {
+            let _ = exec::exec_wait_args("kill", ["-KILL", &pid.to_string()])?;
+        }
+        Ok(true)
+    }
+
+    /// Pids running IDE by SDK dir
+    pub fn get_ide_pids(dir: &str) -> Vec<u32> {
+        utils::get_pids_by_exe(&format!("{}/bin/qtcreator", dir))
+    }
+
+    /// Path to qmlformat from Qt in SDK
+    pub fn get_qmlformat(&self) -> Option<String> {
+        WalkDir::new(&self.dir)
+            .max_depth(5)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .find(|e| e.file_type().is_file() && e.file_name() == "qmlformat")
+            .map(|e| e.path().to_string_lossy().to_string())
+    }
+
     pub fn search() -> Vec<SdkInstalledModel> {
         SdkConfig::load_models()
     }
@@ -86,7 +130,7 @@ impl SdkInstalledModel {
 
     pub fn search_full() -> Result<Vec<SdkInstalledModel>, Box<dyn std::error::Error>> {
         let mut models: Vec<SdkInstalledModel> = vec![];
-        let sdks_path = utils::search_files_by_home("SDKMaintenanceTool");
+        let sdks_path = utils::search_files_by_home("SDKMaintenanceTool", &SDK_KNOWN_DIRS);
         for tools in sdks_path {
             let sdk_dir = tools.replace("/SDKMaintenanceTool", "");
             let sdk_release = sdk_dir.clone() + "/sdk-release";
@@ -98,29 +142,52 @@ impl SdkInstalledModel {
                 Ok(s) => s,
                 Err(_) => continue,
             };
+            /// Version from name library: libUtils.so.4.15.2
+            fn _get_lib_version(sdk_dir: &String, prefix: &str) -> Result<String, Box<dyn Error>> {
+                let version = WalkDir::new(format!("{sdk_dir}/lib"))
+                    .max_depth(3)
+                    .follow_links(false)
+                    .into_iter()
+                    .filter_map(|e| e.ok())
+                    .filter_map(|e| {
+                        e.file_name()
+                            .to_str()
+                            .and_then(|e| e.strip_prefix(prefix))
+                            .map(|e| e.to_string())
+                    })
+                    .find(|e| e.split(".").count() == 3 && e.split(".").all(|e| e.parse::<u32>().is_ok()));
+                match version {
+                    Some(version) => Ok(version),
+                    None => Err(tr!("не удалось найти версию"))?,
+                }
+            }
+
             fn _get_qt_creator_version(sdk_dir: &String) -> Result<String, Box<dyn Error>> {
-                let output = exec::exec_wait_args(&format!("{sdk_dir}/bin/qtcreator"), ["-version"])?;
-                let lines = utils::parse_output(output.stderr);
-                Ok(utils::config_get_string_index(&lines, "Qt Creator", " ", 2)?)
+                _get_lib_version(sdk_dir, "libExtensionSystem.so.")
             }
 
             fn _get_qt_version(sdk_dir: &String) -> Result<String, Box<dyn Error>> {
-                let output = exec::exec_wait_args(&format!("{sdk_dir}/bin/qtcreator"), ["-version"])?;
-                let lines = utils::parse_output(output.stderr);
-                Ok(utils::config_get_string_index(&lines, "Qt Creator", " ", -1)?)
+                _get_lib_version(sdk_dir, "libQt5Core.so.").or_else(|_| _get_lib_version(sdk_dir, "libQt6Core.so."))
             }
 
-            fn _get_build_date(sdk_dir: &String) -> Result<String, Box<dyn Error>> {
-                let output = exec::exec_wait_args(&format!("{sdk_dir}/SDKMaintenanceTool"), ["--version"])?;
-                let lines = utils::parse_output(output.stdout);
-                Ok(utils::config_get_string_index(&lines, "Build date", ":", -1)?)
+            /// Date from sdk-release or installer metadata: <LastUpdateDate>2024-05-14</LastUpdateDate>
+            fn _get_build_date(data: &Vec<String>, sdk_dir: &String) -> Result<String, Box<dyn Error>> {
+                if let Ok(date) = utils::config_get_string(data, "SDK_BUILD_DATE", "=") {
+                    return Ok(date.trim().to_string());
+                }
+                let components = fs::read_to_string(format!("{sdk_dir}/components.xml"))?;
+                let re = Regex::new(r"<LastUpdateDate>\s*(\d{4}-\d{2}-\d{2})")?;
+                match re
+                    .captures_iter(&components)
+                    .filter_map(|e| e.get(1))
+                    .map(|e| e.as_str().to_string())
+                    .max()
+                {
+                    Some(date) => Ok(date),
+                    None => Err(tr!("не удалось найти дату сборки"))?,
+                }
             }
 
-            fn _get_is_running(sdk_dir: &String) -> Result<bool, Box<dyn Error>> {
-                let output = exec::exec_wait_args("ps", ["ax"])?;
-                let lines = utils::parse_output(output.stdout);
-                Ok(lines.iter().any(|e| e.contains(&format!("{}/bin/qtcreator", sdk_dir))))
-            }
             let build_type = if version.contains("-mb2") {
                 SdkBuildType::MB2
             } else {
@@ -133,8 +200,8 @@ impl SdkInstalledModel {
                 version: version.split("-").next().unwrap().to_string(),
                 qt_creator_version: _get_qt_creator_version(&sdk_dir).unwrap_or_else(|_| "undefined".to_string()),
                 qt_version: _get_qt_version(&sdk_dir).unwrap_or_else(|_| "undefined".to_string()),
-                build_date: _get_build_date(&sdk_dir).unwrap_or_else(|_| "undefined".to_string()),
-                is_running: _get_is_running(&sdk_dir).unwrap_or_else(|_| false),
+                build_date: _get_build_date(&data, &sdk_dir).unwrap_or_else(|_| "undefined".to_string()),
+                is_running: !SdkInstalledModel::get_ide_pids(&sdk_dir).is_empty(),
                 build_type,
             });
         }
@@ -1,4 +1,8 @@
+use chrono::NaiveDateTime;
+use chrono::Utc;
 use colored::Colorize;
+use std::fs;
+use std::path::Path;
 
 use crate::models::TraitModel;
 use crate::models::sdk_installed::model::SdkInstalledModel;
@@ -6,11 +10,15 @@ use crate::models::session::model::SessionModel;
 use crate::models::session::model::SessionModelType;
 use crate::service::command::exec;
 use crate::tools::macros::print_info;
+use crate::tools::macros::tr;
 use crate::tools::programs;
 use crate::tools::utils;
 use serde::Deserialize;
 use serde::Serialize;
 
+/// Mount shared home host in engine
+const ENGINE_SHARE_HOME: &str = "/home/mersdk/share";
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SdkInstalledEngineModel {
     pub id: String,
@@ -74,7 +82,6 @@ impl SdkInstalledEngineModel {
         }
     }
 
-    #[allow(dead_code)]
     pub fn stop(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let program = programs::get_vboxmanage()?;
         let output = exec::exec_wait_args(&program, ["controlvm", self.uuid.as_str(), "poweroff"])?;
@@ -86,6 +93,82 @@ impl SdkInstalledEngineModel {
         }
     }
 
+    /// Total memory VM in MB
+    pub fn get_memory(&self) -> Result<u64, Box<dyn std::error::Error>> {
+        match Self::get_vm_info_value(&self.get_vm_info()?, "memory") {
+            Some(value) => Ok(value.parse::<u64>()?),
+            None => Err("не удалось получить память engine")?,
+        }
+    }
+
+    /// Memory used by process VM in MB
+    pub fn get_memory_used(&self) -> Option<u64> {
+        for entry in fs::read_dir("/proc").ok()?.filter_map(|e| e.ok()) {
+            let cmdline = match fs::read(entry.path().join("cmdline")) {
+                Ok(value) => String::from_utf8_lossy(&value).replace('\0', " "),
+                Err(_) => continue,
+            };
+            if !cmdline.contains("VBoxHeadless") || !cmdline.contains(&self.uuid) {
+                continue;
+            }
+            let status = fs::read_to_string(entry.path().join("status")).ok()?;
+            let rss = status
+                .lines()
+                .find(|e| e.starts_with("VmRSS:"))?
+                .split_whitespace()
+                .nth(1)?
+                .parse::<u64>()
+                .ok()?;
+            return Some(rss / 1024);
+        }
+        None
+    }
+
+    /// Uptime VM in seconds
+    pub fn get_uptime(&self) -> Option<u64> {
+        if !self.is_running {
+            return None;
+        }
+        let time = Self::get_vm_info_value(&self.get_vm_info().ok()?, "VMStateChangeTime")?;
+        let time = NaiveDateTime::parse_from_str(&time, "%Y-%m-%dT%H:%M:%S%.f").ok()?;
+        let seconds = (Utc::now().naive_utc() - time).num_seconds();
+        if seconds < 0 { None } else { Some(seconds as u64) }
+    }
+
+    /// Map host path to shared home engine
+    pub fn get_remote_path(&self, path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+        let lines = self.get_vm_info()?;
+        let mut index = 1;
+        while let Some(name) = Self::get_vm_info_value(&lines, &format!("SharedFolderNameMachineMapping{index}")) {
+            let host = Self::get_vm_info_value(&lines, &format!("SharedFolderPathMachineMapping{index}"));
+            if let Some(relative) = host
+                .filter(|_| name == "home")
+                .and_then(|e| path.strip_prefix(e).ok().map(|e| e.to_string_lossy().to_string()))
+            {
+                return Ok(format!("{}/{}", ENGINE_SHARE_HOME, relative)
+                    .trim_end_matches('/')
+                    .to_string());
+            }
+            index += 1;
+        }
+        Err(tr!("проект должен находиться в общей с engine директории"))?
+    }
+
+    /// Data `showvminfo --machinereadable`
+    fn get_vm_info(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let program = programs::get_vboxmanage()?;
+        let output = exec::exec_wait_args(&program, ["showvminfo", self.uuid.as_str(), "--machinereadable"])?;
+        Ok(utils::parse_output(output.stdout))
+    }
+
+    fn get_vm_info_value(lines: &[String], key: &str) -> Option<String> {
+        let prefix = format!("{key}=");
+        lines
+            .iter()
+            .find(|e| e.starts_with(&prefix))
+            .map(|e| e.trim_start_matches(&prefix).trim_matches('"').to_string())
+    }
+
     pub fn get_sdk_engine(sdk: &SdkInstalledModel) -> Result<SdkInstalledEngineModel, Box<dyn std::error::Error>> {
         let program = programs::get_vboxmanage()?;
         let output = exec::exec_wait_args(&program, ["list", "vms"])?;
@@ -248,6 +248,24 @@ impl SessionModel {
         Ok(())
     }
 
+    pub fn call(&self, command: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let output = self.session.call(command)?;
+        Ok(output
+            .join("")
+            .split("\n")
+            .filter(|e| !e.trim().is_empty())
+            .map(|e| e.to_string())
+            .collect())
+    }
+
+    pub fn call_callback<T: FnMut(String)>(
+        &self,
+        command: &str,
+        callback: T,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.session.call_callback(command, callback)
+    }
+
     pub fn close(&self) -> Result<(), Box<dyn std::error::Error>> {
         tokio::task::block_in_place(|| Handle::current().block_on(self.session.close()))?;
         Ok(())
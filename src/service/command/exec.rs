@@ -1,6 +1,7 @@
 use std::ffi::OsStr;
 use std::io::BufRead;
 use std::io::BufReader;
+use std::path::Path;
 use std::process::Command;
 use std::process::Output;
 use std::process::Stdio;
@@ -36,6 +37,23 @@ where
     }
 }
 
+pub fn exec_wait_args_dir<I, S>(program: &str, args: I, dir: &Path) -> Result<Output, Box<dyn std::error::Error>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    match Command::new(program)
+        .args(args)
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+    {
+        Ok(output) => Ok(output),
+        Err(_) => Err(tr!("команда завершилась неудачей"))?,
+    }
+}
+
 pub fn exec_wait_args_callback<I, S, T: FnMut(String)>(
     program: &str,
     args: I,
@@ -138,3 +156,21 @@ where
         Err(tr!("команда завершилась неудачей"))?
     }
 }
+
+/// Run in background without output, process live after exit app
+pub fn exec_spawn_args<I, S>(program: &str, args: I) -> Result<(), Box<dyn std::error::Error>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    match Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(_) => Ok(()),
+        Err(_) => Err(tr!("команда завершилась неудачей"))?,
+    }
+}
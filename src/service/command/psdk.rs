@@ -4,9 +4,11 @@ use crate::models::psdk_target_package::model::PsdkTargetPackageModel;
 use crate::service::command::exec;
 use crate::tools::constants;
 use crate::tools::macros::tr;
+use crate::tools::programs;
 use crate::tools::single;
 use crate::tools::utils;
 use std::fs;
+use std::path::Path;
 use std::path::PathBuf;
 
 pub fn psdk_targets_exec(chroot: &String) -> Result<Vec<String>, Box<dyn std::error::Error>> {
@@ -14,6 +16,94 @@ pub fn psdk_targets_exec(chroot: &String) -> Result<Vec<String>, Box<dyn std::er
     Ok(utils::parse_output(output.stdout))
 }
 
+pub fn tooling_create<T: FnMut(String)>(
+    chroot: &str,
+    name: &str,
+    path: &Path,
+    callback: T,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let sudo = programs::get_sudo()?;
+    exec::exec_wait_args_callback(
+        &sudo,
+        [
+            "-n",
+            chroot,
+            "sdk-assistant",
+            "tooling",
+            "create",
+            "-y",
+            name,
+            &path.to_string_lossy(),
+        ],
+        callback,
+    )
+}
+
+pub fn tooling_remove(chroot: &str, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    match exec::exec_wait_args_sudo(chroot, ["sdk-assistant", "tooling", "remove", "-y", name]) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(tr!("не удалось удалить Tooling: {}", name))?,
+    }
+}
+
+pub fn target_create<T: FnMut(String)>(
+    chroot: &str,
+    name: &str,
+    tooling: &str,
+    path: &Path,
+    callback: T,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let sudo = programs::get_sudo()?;
+    exec::exec_wait_args_callback(
+        &sudo,
+        [
+            "-n",
+            chroot,
+            "sdk-assistant",
+            "target",
+            "create",
+            "-y",
+            "--tooling",
+            tooling,
+            name,
+            &path.to_string_lossy(),
+        ],
+        callback,
+    )
+}
+
+pub fn target_remove(chroot: &str, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    match exec::exec_wait_args_sudo(chroot, ["sdk-assistant", "target", "remove", "-y", name]) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(tr!("не удалось удалить Target: {}", name))?,
+    }
+}
+
+pub fn project_configure(
+    chroot: &str,
+    target: &PsdkTargetModel,
+    path: &Path,
+    build_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match exec::exec_wait_args_sudo(
+        chroot,
+        [
+            "sb2",
+            "-t",
+            &target.full_name,
+            "cmake",
+            "-S",
+            &path.to_string_lossy(),
+            "-B",
+            &build_dir.to_string_lossy(),
+            "-DCMAKE_EXPORT_COMPILE_COMMANDS=ON",
+        ],
+    ) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(tr!("не удалось выполнить конфигурацию CMake проекта"))?,
+    }
+}
+
 pub fn target_package_install(
     chroot: &String,
     path: &PathBuf,
@@ -91,6 +181,44 @@ pub fn target_package_remove(
     Ok(removed)
 }
 
+pub fn rpm_validate(
+    chroot: &str,
+    target: &PsdkTargetModel,
+    path: &PathBuf,
+    profile: &str,
+) -> Result<(bool, Vec<String>), Box<dyn std::error::Error>> {
+    let sudo = programs::get_sudo()?;
+    let file_path = add_temp_file_flatpak_for_psdk(path);
+    let output = exec::exec_wait_args(
+        &sudo,
+        [
+            "-n",
+            chroot,
+            "sb2",
+            "-t",
+            &target.full_name,
+            "-m",
+            "emulate",
+            "rpm-validator",
+            "-p",
+            profile,
+            &file_path.to_string_lossy(),
+        ],
+    );
+    del_temp_file_flatpak_for_psdk(&file_path);
+    // Validator exit with error if package not valid, output needed anyway
+    let output = output?;
+    let success = output.status.success();
+    let mut lines = utils::parse_output(output.stdout);
+    lines.append(&mut utils::parse_output(output.stderr));
+    if !success && lines.iter().any(|e| e.contains("sudo:")) {
+        Err(tr!(
+            "нет доступа к sudo, для работы с Platform SDK необходимо добавить sudoers"
+        ))?
+    }
+    Ok((success, lines))
+}
+
 pub fn rpm_is_sign(chroot: &String, path: &PathBuf) -> bool {
     let file_path = add_temp_file_flatpak_for_psdk(path);
     let output = match exec::exec_wait_args_sudo(
@@ -168,6 +296,10 @@ fn _get_regular_cert() -> Option<PathBuf> {
 }
 
 pub fn add_sudoers_chroot_access(models: &Vec<PsdkInstalledModel>) -> Result<(), Box<dyn std::error::Error>> {
+    // Nothing to access - clear records
+    if models.is_empty() {
+        return remove_sudoers_chroot_access();
+    }
     // Get user name
     let user_name = utils::get_user_name();
     // Create files
@@ -202,6 +334,40 @@ pub fn add_sudoers_chroot_access(models: &Vec<PsdkInstalledModel>) -> Result<(),
     Ok(())
 }
 
+/// Get Platform SDK dirs from sudoers records
+pub fn get_sudoers_chroot_access() -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let mut dirs: Vec<String> = vec![];
+    for line in utils::read_file_sudoers(constants::PSDK_CHROOT)? {
+        if line.starts_with("Defaults") {
+            continue;
+        }
+        if let Some(path) = line.split("NOPASSWD:").nth(1) {
+            let dir = path.trim().trim_end_matches("/sdk-chroot").to_string();
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+    }
+    Ok(dirs)
+}
+
+/// Check access to chroot without password
+pub fn is_sudoers_chroot_access(model: &PsdkInstalledModel) -> bool {
+    match programs::get_sudo() {
+        Ok(sudo) => match exec::exec_wait_args(&sudo, ["-n", "-l", &model.chroot]) {
+            Ok(output) => output.status.success(),
+            Err(_) => false,
+        },
+        Err(_) => false,
+    }
+}
+
+pub fn remove_sudoers_chroot_access() -> Result<(), Box<dyn std::error::Error>> {
+    utils::remove_file_sudoers(constants::PSDK_CHROOT)?;
+    utils::remove_file_sudoers(constants::MER_PSDK_CHROOT)?;
+    Ok(())
+}
+
 // Create temp file for psdk
 // psdk can't mount flatpak path files
 pub fn add_temp_file_flatpak_for_psdk(path: &PathBuf) -> PathBuf {
@@ -1,4 +1,5 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use dbus::Message;
 use dbus::Path;
@@ -15,9 +16,11 @@ use tokio::runtime::Handle;
 
 use crate::feature::app_auth_login::incoming::AppAuthLoginIncoming;
 use crate::feature::app_auth_logout::incoming::AppAuthLogoutIncoming;
+use crate::feature::app_doctor::incoming::AppDoctorIncoming;
 use crate::feature::app_info::incoming::AppInfoIncoming;
 use crate::feature::app_open_dir::incoming::AppOpenDirIncoming;
 use crate::feature::app_open_file::incoming::AppOpenFileIncoming;
+use crate::feature::app_updates::incoming::AppUpdatesIncoming;
 use crate::feature::demo_app_info::incoming::DemoAppInfoIncoming;
 use crate::feature::device_info::incoming::DeviceInfoIncoming;
 use crate::feature::device_package_install::incoming::DevicePackageInstallIncoming;
@@ -44,27 +47,44 @@ use crate::feature::flutter_download::incoming::FlutterDownloadIncoming;
 use crate::feature::flutter_info::incoming::FlutterInfoIncoming;
 use crate::feature::flutter_install::incoming::FlutterInstallIncoming;
 use crate::feature::flutter_project_format::incoming::FlutterProjectFormatIncoming;
+use crate::feature::flutter_project_mirror::incoming::FlutterProjectMirrorIncoming;
 use crate::feature::flutter_project_report::incoming::FlutterProjectReportIncoming;
+use crate::feature::flutter_project_run::incoming::FlutterProjectRunIncoming;
 use crate::feature::flutter_sync::incoming::FlutterSyncIncoming;
 use crate::feature::flutter_terminal::incoming::FlutterTerminalIncoming;
 use crate::feature::flutter_uninstall::incoming::FlutterUninstallIncoming;
+use crate::feature::flutter_use::incoming::FlutterUseIncoming;
+use crate::feature::package_diff::incoming::PackageDiffIncoming;
+use crate::feature::package_inspect::incoming::PackageInspectIncoming;
+use crate::feature::project_lint::incoming::ProjectLintIncoming;
+use crate::feature::project_new::incoming::ProjectNewIncoming;
+use crate::feature::project_validate::incoming::ProjectValidateIncoming;
 use crate::feature::psdk_available::incoming::PsdkAvailableIncoming;
 use crate::feature::psdk_download::incoming::PsdkDownloadIncoming;
 use crate::feature::psdk_info::incoming::PsdkInfoIncoming;
 use crate::feature::psdk_install::incoming::PsdkInstallIncoming;
 use crate::feature::psdk_package_sign::incoming::PsdkPackageSignIncoming;
+use crate::feature::psdk_package_validate::incoming::PsdkPackageValidateIncoming;
+use crate::feature::psdk_project_ide::incoming::PsdkProjectIdeIncoming;
 use crate::feature::psdk_sync::incoming::PsdkSyncIncoming;
+use crate::feature::psdk_target_install::incoming::PsdkTargetInstallIncoming;
 use crate::feature::psdk_target_package_find::incoming::PsdkTargetPackageFindIncoming;
 use crate::feature::psdk_target_package_install::incoming::PsdkTargetPackageInstallIncoming;
 use crate::feature::psdk_target_package_uninstall::incoming::PsdkTargetPackageUninstallIncoming;
+use crate::feature::psdk_target_uninstall::incoming::PsdkTargetUninstallIncoming;
 use crate::feature::psdk_terminal::incoming::PsdkTerminalIncoming;
+use crate::feature::psdk_tooling_uninstall::incoming::PsdkToolingUninstallIncoming;
 use crate::feature::psdk_uninstall::incoming::PsdkUninstallIncoming;
 use crate::feature::sdk_available::incoming::SdkAvailableIncoming;
 use crate::feature::sdk_download::incoming::SdkDownloadIncoming;
+use crate::feature::sdk_engine_start::incoming::SdkEngineStartIncoming;
+use crate::feature::sdk_engine_status::incoming::SdkEngineStatusIncoming;
+use crate::feature::sdk_engine_stop::incoming::SdkEngineStopIncoming;
 use crate::feature::sdk_ide_close::incoming::SdkIdeCloseIncoming;
 use crate::feature::sdk_ide_open::incoming::SdkIdeOpenIncoming;
 use crate::feature::sdk_info::incoming::SdkInfoIncoming;
 use crate::feature::sdk_install::incoming::SdkInstallIncoming;
+use crate::feature::sdk_project_build::incoming::SdkProjectBuildIncoming;
 use crate::feature::sdk_project_format::incoming::SdkProjectFormatIncoming;
 use crate::feature::sdk_sync::incoming::SdkSyncIncoming;
 use crate::feature::sdk_terminal::incoming::SdkTerminalIncoming;
@@ -116,9 +136,11 @@ impl ServerDbus {
             // App
             AppAuthLoginIncoming::dbus_method_run(builder);
             AppAuthLogoutIncoming::dbus_method_run(builder);
+            AppDoctorIncoming::dbus_method_run(builder);
             AppInfoIncoming::dbus_method_run(builder);
             AppOpenDirIncoming::dbus_method_run(builder);
             AppOpenFileIncoming::dbus_method_run(builder);
+            AppUpdatesIncoming::dbus_method_run(builder);
 
             /////////////////
             // Demo App
@@ -221,20 +243,51 @@ impl ServerDbus {
 
             FlutterProjectFormatIncoming::dbus_method_run(builder);
             FlutterProjectFormatIncoming::dbus_method_run_by_id(builder);
+            FlutterProjectFormatIncoming::dbus_method_run_mode(builder);
+            FlutterProjectFormatIncoming::dbus_method_run_mode_by_id(builder);
+
+            FlutterProjectMirrorIncoming::dbus_method_run(builder);
+            FlutterProjectMirrorIncoming::dbus_method_run_dir(builder);
 
             FlutterProjectReportIncoming::dbus_method_run_path(builder);
             FlutterProjectReportIncoming::dbus_method_run_path_by_id(builder);
+            FlutterProjectReportIncoming::dbus_method_run_path_format(builder);
             FlutterProjectReportIncoming::dbus_method_run_url(builder);
             FlutterProjectReportIncoming::dbus_method_run_url_by_id(builder);
+            FlutterProjectReportIncoming::dbus_method_run_url_format(builder);
+
+            FlutterProjectRunIncoming::dbus_method_run(builder);
+            FlutterProjectRunIncoming::dbus_method_run_by_id(builder);
+            FlutterProjectRunIncoming::dbus_method_run_device_by_id(builder);
+            FlutterProjectRunIncoming::dbus_method_run_target_by_id(builder);
 
             FlutterSyncIncoming::dbus_method_run(builder);
 
             FlutterTerminalIncoming::dbus_method_run(builder);
             FlutterTerminalIncoming::dbus_method_run_by_id(builder);
+            FlutterTerminalIncoming::dbus_method_run_path(builder);
 
             FlutterUninstallIncoming::dbus_method_run(builder);
             FlutterUninstallIncoming::dbus_method_run_by_id(builder);
 
+            FlutterUseIncoming::dbus_method_run(builder);
+            FlutterUseIncoming::dbus_method_run_path(builder);
+            FlutterUseIncoming::dbus_method_run_pin(builder);
+
+            /////////////////
+            // Package
+            PackageDiffIncoming::dbus_method_run(builder);
+
+            PackageInspectIncoming::dbus_method_run(builder);
+
+            /////////////////
+            // Project
+            ProjectLintIncoming::dbus_method_run(builder);
+
+            ProjectNewIncoming::dbus_method_run(builder);
+
+            ProjectValidateIncoming::dbus_method_run(builder);
+
             /////////////////
             // Psdk
             PsdkAvailableIncoming::dbus_method_run(builder);
@@ -243,17 +296,39 @@ impl ServerDbus {
             PsdkPackageSignIncoming::dbus_method_run(builder);
             PsdkPackageSignIncoming::dbus_method_run_by_id(builder);
 
+            PsdkPackageValidateIncoming::dbus_method_run(builder);
+            PsdkPackageValidateIncoming::dbus_method_run_by_id(builder);
+            PsdkPackageValidateIncoming::dbus_method_run_target_by_id(builder);
+
+            PsdkProjectIdeIncoming::dbus_method_run(builder);
+            PsdkProjectIdeIncoming::dbus_method_run_by_id(builder);
+            PsdkProjectIdeIncoming::dbus_method_run_target_by_id(builder);
+
+            PsdkTargetInstallIncoming::dbus_method_run(builder);
+            PsdkTargetInstallIncoming::dbus_method_run_by_id(builder);
+            PsdkTargetInstallIncoming::dbus_method_run_path(builder);
+            PsdkTargetInstallIncoming::dbus_method_run_path_by_id(builder);
+
             PsdkTargetPackageFindIncoming::dbus_method_run(builder);
             PsdkTargetPackageFindIncoming::dbus_method_run_by_id(builder);
             PsdkTargetPackageFindIncoming::dbus_method_run_target_by_id(builder);
 
             PsdkTargetPackageInstallIncoming::dbus_method_run(builder);
             PsdkTargetPackageInstallIncoming::dbus_method_run_by_id(builder);
+            PsdkTargetPackageInstallIncoming::dbus_method_run_target_by_id(builder);
 
             PsdkTargetPackageUninstallIncoming::dbus_method_run(builder);
             PsdkTargetPackageUninstallIncoming::dbus_method_run_by_id(builder);
             PsdkTargetPackageUninstallIncoming::dbus_method_run_target_by_id(builder);
 
+            PsdkTargetUninstallIncoming::dbus_method_run(builder);
+            PsdkTargetUninstallIncoming::dbus_method_run_by_id(builder);
+            PsdkTargetUninstallIncoming::dbus_method_run_target_by_id(builder);
+
+            PsdkToolingUninstallIncoming::dbus_method_run(builder);
+            PsdkToolingUninstallIncoming::dbus_method_run_by_id(builder);
+            PsdkToolingUninstallIncoming::dbus_method_run_tooling_by_id(builder);
+
             PsdkDownloadIncoming::dbus_method_run(builder);
             PsdkDownloadIncoming::dbus_method_run_by_id(builder);
 
@@ -279,11 +354,22 @@ impl ServerDbus {
             SdkDownloadIncoming::dbus_method_run(builder);
             SdkDownloadIncoming::dbus_method_run_by_id(builder);
 
+            SdkEngineStartIncoming::dbus_method_run(builder);
+            SdkEngineStartIncoming::dbus_method_run_by_id(builder);
+
+            SdkEngineStatusIncoming::dbus_method_run(builder);
+            SdkEngineStatusIncoming::dbus_method_run_by_id(builder);
+
+            SdkEngineStopIncoming::dbus_method_run(builder);
+            SdkEngineStopIncoming::dbus_method_run_by_id(builder);
+
             SdkIdeCloseIncoming::dbus_method_run(builder);
             SdkIdeCloseIncoming::dbus_method_run_by_id(builder);
 
             SdkIdeOpenIncoming::dbus_method_run(builder);
             SdkIdeOpenIncoming::dbus_method_run_by_id(builder);
+            SdkIdeOpenIncoming::dbus_method_run_path(builder);
+            SdkIdeOpenIncoming::dbus_method_run_path_by_id(builder);
 
             SdkInfoIncoming::dbus_method_run(builder);
             SdkInfoIncoming::dbus_method_run_by_id(builder);
@@ -291,8 +377,14 @@ impl ServerDbus {
             SdkInstallIncoming::dbus_method_run(builder);
             SdkInstallIncoming::dbus_method_run_by_id(builder);
 
+            SdkProjectBuildIncoming::dbus_method_run(builder);
+            SdkProjectBuildIncoming::dbus_method_run_by_id(builder);
+            SdkProjectBuildIncoming::dbus_method_run_target_by_id(builder);
+
             SdkProjectFormatIncoming::dbus_method_run(builder);
             SdkProjectFormatIncoming::dbus_method_run_by_id(builder);
+            SdkProjectFormatIncoming::dbus_method_run_mode(builder);
+            SdkProjectFormatIncoming::dbus_method_run_mode_by_id(builder);
 
             SdkSyncIncoming::dbus_method_run(builder);
 
@@ -340,10 +432,23 @@ impl ServerDbus {
             Handle::current().block_on(single::get_dbus().connection.request_name(name, false, true, false))
         })?;
         print_success!("Сервис D-Bus запущен!");
+        tokio::spawn(AppUpdatesIncoming::watch());
         tokio::task::block_in_place(|| Handle::current().block_on(future::pending::<()>()));
         unreachable!()
     }
 
+    /// Check D-Bus service name has owner
+    pub fn is_running() -> bool {
+        fn _exec() -> Result<bool, Box<dyn std::error::Error>> {
+            let connection = dbus::blocking::Connection::new_session()?;
+            let proxy = connection.with_proxy("org.freedesktop.DBus", "/org/freedesktop/DBus", Duration::from_secs(2));
+            let (has_owner,): (bool,) =
+                proxy.method_call("org.freedesktop.DBus", "NameHasOwner", (constants::DBUS_NAME,))?;
+            Ok(has_owner)
+        }
+        _exec().unwrap_or(false)
+    }
+
     pub fn send(outgoing: String) {
         let path: Path<'static> = format!("{}", "/api").into();
         let msg = Message::signal(&path, &constants::DBUS_NAME.into(), &"Listen".into()).append1(outgoing);
@@ -7,6 +7,9 @@ pub mod dbus {
     pub mod methods;
     pub mod server;
 }
+pub mod pub_mirror {
+    pub mod server;
+}
 pub mod requests {
     pub mod client;
     pub mod methods;
@@ -0,0 +1,155 @@
+use std::fs;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::path::Path;
+use std::path::PathBuf;
+use std::thread;
+
+use human_sort::compare;
+use serde_json::json;
+
+use crate::service::responses::dart_package::DartPackageArchiveResponse;
+use crate::tools::macros::print_info;
+use crate::tools::macros::print_success;
+use crate::tools::macros::tr;
+
+/// Content type API pub repository v2
+const CONTENT_TYPE_PUB: &str = "application/vnd.pub.v2+json";
+
+/// Local server pub packages from mirror dir, API: https://github.com/dart-lang/pub/blob/master/doc/repository-spec-v2.md
+pub struct ServerPub {}
+
+impl ServerPub {
+    pub fn run(dir: PathBuf, port: u16) -> Result<(), Box<dyn std::error::Error>> {
+        if !dir.is_dir() {
+            Err(tr!("директория зеркала не найдена: {}", dir.to_string_lossy()))?
+        }
+        let listener = match TcpListener::bind(("127.0.0.1", port)) {
+            Ok(value) => value,
+            Err(_) => Err(tr!("не удалось запустить сервер на порту {}", port))?,
+        };
+        let message = tr!("сервер пакетов запущен: http://127.0.0.1:{}", port);
+        print_success!(message);
+        let message = tr!("выполните: PUB_HOSTED_URL=http://127.0.0.1:{} flutter pub get", port);
+        print_info!(message);
+        for stream in listener.incoming().filter_map(|e| e.ok()) {
+            let dir = dir.clone();
+            thread::spawn(move || {
+                let _ = Self::handle(stream, &dir, port);
+            });
+        }
+        Ok(())
+    }
+
+    fn handle(mut stream: TcpStream, dir: &Path, port: u16) -> Result<(), Box<dyn std::error::Error>> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut request = String::new();
+        reader.read_line(&mut request)?;
+        // Headers, host for archive url
+        let mut host = format!("127.0.0.1:{}", port);
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+            let value = header
+                .split_once(":")
+                .filter(|(key, _)| key.trim().eq_ignore_ascii_case("host"))
+                .map(|(_, value)| value.trim().to_string());
+            if let Some(value) = value {
+                host = value;
+            }
+        }
+        let parts = request.split_whitespace().collect::<Vec<&str>>();
+        let method = parts.first().copied().unwrap_or_default();
+        let target = parts.get(1).copied().unwrap_or_default();
+        let target = target.split("?").next().unwrap_or_default();
+        let is_head = method == "HEAD";
+        if method != "GET" && !is_head {
+            return Self::response(&mut stream, "405 Method Not Allowed", "text/plain", b"", false);
+        }
+        let segments = target.trim_matches('/').split("/").collect::<Vec<&str>>();
+        match segments.as_slice() {
+            // GET /api/packages/<name>
+            ["api", "packages", name] if Self::is_name(name) => {
+                match Self::package(dir, name, &format!("http://{}", host)) {
+                    Some(body) => Self::response(&mut stream, "200 OK", CONTENT_TYPE_PUB, body.as_bytes(), is_head),
+                    None => Self::not_found(&mut stream, is_head),
+                }
+            }
+            // GET /packages/<name>/versions/<version>.tar.gz
+            ["packages", name, "versions", file] if Self::is_name(name) && !file.contains("..") => {
+                match fs::read(dir.join("packages").join(name).join("versions").join(file)) {
+                    Ok(body) if file.ends_with(".tar.gz") => {
+                        Self::response(&mut stream, "200 OK", "application/octet-stream", &body, is_head)
+                    }
+                    _ => Self::not_found(&mut stream, is_head),
+                }
+            }
+            _ => Self::not_found(&mut stream, is_head),
+        }
+    }
+
+    /// Name package: a-z, 0-9, _
+    fn is_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|e| e.is_ascii_lowercase() || e.is_ascii_digit() || e == '_')
+    }
+
+    /// Info package with versions from mirror
+    fn package(dir: &Path, name: &str, url: &str) -> Option<String> {
+        let path = dir.join("packages").join(name).join("versions");
+        let mut versions = fs::read_dir(&path)
+            .ok()?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|e| e == "json"))
+            .filter_map(|e| fs::read_to_string(e.path()).ok())
+            .filter_map(|e| serde_json::from_str::<DartPackageArchiveResponse>(&e).ok())
+            .filter(|e| path.join(format!("{}.tar.gz", e.version)).exists())
+            .collect::<Vec<DartPackageArchiveResponse>>();
+        versions.sort_by(|a, b| compare(&a.version, &b.version));
+        for version in versions.iter_mut() {
+            version.archive_url = format!("{}/packages/{}/versions/{}.tar.gz", url, name, version.version);
+        }
+        let latest = versions.last()?.clone();
+        Some(
+            json!({
+                "name": name,
+                "latest": latest,
+                "versions": versions,
+            })
+            .to_string(),
+        )
+    }
+
+    fn not_found(stream: &mut TcpStream, is_head: bool) -> Result<(), Box<dyn std::error::Error>> {
+        let body = json!({"error": {"code": "NotFound", "message": "not found"}}).to_string();
+        Self::response(stream, "404 Not Found", "application/json", body.as_bytes(), is_head)
+    }
+
+    fn response(
+        stream: &mut TcpStream,
+        status: &str,
+        content_type: &str,
+        body: &[u8],
+        is_head: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let headers = format!(
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            status,
+            content_type,
+            body.len()
+        );
+        stream.write_all(headers.as_bytes())?;
+        if !is_head {
+            stream.write_all(body)?;
+        }
+        stream.flush()?;
+        Ok(())
+    }
+}
@@ -2,9 +2,11 @@ use futures::StreamExt;
 use futures::stream::FuturesUnordered;
 use std::collections::HashMap;
 use std::env;
+use std::fs;
 use std::fs::File;
 use std::io::Write;
 use std::os::unix::fs::MetadataExt;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
@@ -14,10 +16,13 @@ use tokio::runtime::Handle;
 use crate::feature::incoming::DataIncoming;
 use crate::feature::incoming::TraitIncoming;
 use crate::feature::selector::incoming::SelectorCmdIncoming;
+use crate::models::pubspec::model::PubspecLockModel;
 use crate::models::pubspec::model::PubspecModel;
 use crate::service::requests::client::ClientRequest;
 use crate::service::responses::common::CommonResponse;
+use crate::service::responses::dart_package::DartPackageArchiveResponse;
 use crate::service::responses::dart_package::DartPackageResponse;
+use crate::service::responses::dart_package::DartPackageScoreResponse;
 use crate::service::responses::demo_releases::DemoAppResponse;
 use crate::service::responses::demo_releases::DemoReleasesResponse;
 use crate::service::responses::faq::FaqResponse;
@@ -25,6 +30,8 @@ use crate::service::responses::faq::FaqResponses;
 use crate::service::responses::gitlab_tags::GitlabTagsResponse;
 use crate::service::responses::user::UserResponse;
 use crate::tools::constants;
+use crate::tools::license_utils;
+use crate::tools::license_utils::LicenseStatus;
 use crate::tools::macros::crash;
 use crate::tools::macros::tr;
 use crate::tools::utils;
@@ -191,6 +198,44 @@ impl ClientRequest {
         return result;
     }
 
+    // Get Aurora plugins from gitlab tags repos OMP
+    pub fn get_repo_tags_flutter_plugins(&self) -> Vec<GitlabTagsResponse> {
+        let mut result: Vec<GitlabTagsResponse> = vec![];
+        for project in [
+            "omprussia%2Fflutter%2Fflutter-community-plugins",
+            "omprussia%2Fflutter%2Fflutter-plugins",
+        ] {
+            // Tags by pages, next page in header
+            let mut page = "1".to_string();
+            loop {
+                let url =
+                    format!("https://gitlab.com/api/v4/projects/{project}/repository/tags?per_page=100&page={page}");
+                let response = match self.get_request_auth(url) {
+                    Ok(response) => response,
+                    Err(_) => break,
+                };
+                let next = response
+                    .headers()
+                    .get("x-next-page")
+                    .and_then(|e| e.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string();
+                let body = match tokio::task::block_in_place(|| Handle::current().block_on(response.text())) {
+                    Ok(value) => value,
+                    Err(_) => break,
+                };
+                if let Ok(mut value) = serde_json::from_str::<Vec<GitlabTagsResponse>>(&body) {
+                    result.append(&mut value);
+                }
+                if next.is_empty() {
+                    break;
+                }
+                page = next;
+            }
+        }
+        result
+    }
+
     // Get demos applications from repo
     pub fn get_demo_apps(&self) -> Vec<DemoReleasesResponse> {
         // Get info all packages async
@@ -414,10 +459,44 @@ impl ClientRequest {
         }
     }
 
+    /// Download archive dart package to mirror: packages/{name}/versions/{version}.tar.gz
+    pub fn download_dart_package(
+        &self,
+        package_name: &str,
+        version: &str,
+        dir: &Path,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let path = dir.join("packages").join(package_name).join("versions");
+        let path_archive = path.join(format!("{version}.tar.gz"));
+        let path_info = path.join(format!("{version}.json"));
+        if path_archive.exists() && path_info.exists() {
+            return Ok(false);
+        }
+        fs::create_dir_all(&path)?;
+        // Info version with pubspec
+        let url = format!("https://pub.dev/api/packages/{package_name}/versions/{version}");
+        let response = self.get_request(url)?;
+        let body = tokio::task::block_in_place(|| Handle::current().block_on(response.text()))?;
+        let info = match serde_json::from_str::<DartPackageArchiveResponse>(&body) {
+            Ok(value) => value,
+            Err(_) => Err(tr!("пакет {} {} не найден", package_name, version))?,
+        };
+        // Archive
+        let response = self.get_request(info.archive_url.clone())?;
+        if !response.status().is_success() {
+            Err(tr!("не удалось скачать архив {} {}", package_name, version))?
+        }
+        let bytes = tokio::task::block_in_place(|| Handle::current().block_on(response.bytes()))?;
+        fs::write(&path_archive, bytes)?;
+        fs::write(&path_info, serde_json::to_string_pretty(&info)?)?;
+        Ok(true)
+    }
+
     /// Get dart packages by spec versions
     pub fn get_dart_packages<T: Fn(i32) + Send + Copy + Sync + 'static>(
         &self,
         names_dependencies: &Vec<String>,
+        locks: &HashMap<String, PubspecLockModel>,
         state: T,
     ) -> Result<Vec<PubspecModel>, Box<dyn std::error::Error>> {
         let mut models: Vec<PubspecModel> = vec![];
@@ -429,7 +508,7 @@ impl ClientRequest {
             if per < 100 {
                 state(per as i32);
             }
-            let _ = self._get_dart_packages(1, package_name.clone(), &mut models);
+            let _ = self._get_dart_packages(1, package_name.clone(), locks, &mut models);
         }
         state(100);
         Ok(models)
@@ -440,6 +519,7 @@ impl ClientRequest {
         &self,
         level: i32,
         package_name: String,
+        locks: &HashMap<String, PubspecLockModel>,
         models: &mut Vec<PubspecModel>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         if package_name == "flutter" {
@@ -448,46 +528,141 @@ impl ClientRequest {
         if models.iter().any(|e| e.name == package_name) {
             return Ok(());
         }
-        let (package, dependencies) = tokio::task::block_in_place(|| {
-            Handle::current().block_on(Self::_get_dart_package(self, package_name, level))
-        })?;
+        let lock = locks.get(&package_name);
+        // Sdk dependency: flutter_localizations, sky_engine
+        if lock.is_some_and(|e| e.source == "sdk") {
+            return Ok(());
+        }
+        // Local sources path and git dependency
+        let local = lock.and_then(|lock| {
+            let path = lock.path.as_ref()?.join("pubspec.yaml");
+            let mut package = PubspecModel::parse_model(&path).ok()?;
+            package.level = level;
+            package.version_lock = Some(lock.version.clone());
+            package.source = Some(lock.source.clone());
+            Some((package, PubspecModel::parse_dependencies(&path).ok()?))
+        });
+        let (mut package, dependencies) = match local {
+            Some(value) => value,
+            None => match tokio::task::block_in_place(|| {
+                Handle::current().block_on(Self::_get_dart_package(self, package_name.clone(), level, lock))
+            }) {
+                Ok(value) => value,
+                // Git or path dependency without sources and not found on pub.dev
+                Err(_) if lock.is_some_and(|e| e.source != "hosted") => (
+                    PubspecModel {
+                        name: package_name,
+                        version: lock.unwrap().version.clone(),
+                        description: None,
+                        repository: None,
+                        pub_dev: None,
+                        is_plugin: false,
+                        is_native: false,
+                        platforms: vec![],
+                        aurora: None,
+                        version_lock: lock.map(|e| e.version.clone()),
+                        source: lock.map(|e| e.source.clone()),
+                        dependencies: vec![],
+                        license: None,
+                        license_status: None,
+                        level,
+                    },
+                    vec![],
+                ),
+                Err(error) => Err(error)?,
+            },
+        };
+        // Edges graph, with lock only resolved
+        package.dependencies = dependencies
+            .into_iter()
+            .filter(|e| e != "flutter")
+            .filter(|e| match locks.get(e) {
+                Some(lock) => lock.source != "sdk",
+                None => locks.is_empty(),
+            })
+            .collect();
+        let dependencies = package.dependencies.clone();
         // Add to array
         models.push(package);
         // Load dependencies
-        if let Some(dependencies) = dependencies {
-            for (key, _) in dependencies.as_object().unwrap().into_iter() {
-                self._get_dart_packages(level + 1, key.as_str().to_string(), models)?;
-            }
-        };
+        for key in dependencies {
+            self._get_dart_packages(level + 1, key, locks, models)?;
+        }
         Ok(())
     }
 
-    // Async get package
+    // Async get package, version from lock or latest
     async fn _get_dart_package(
         client: &ClientRequest,
         package_name: String,
         level: i32,
-    ) -> Result<(PubspecModel, Option<serde_json::Value>), Box<dyn std::error::Error>> {
+        lock: Option<&PubspecLockModel>,
+    ) -> Result<(PubspecModel, Vec<String>), Box<dyn std::error::Error>> {
         let url = format!("https://pub.dev/api/packages/{package_name}");
         let url_pub = format!("https://pub.dev/packages/{}", package_name);
         let response = client.get_request(url)?;
         let body = response.text().await?;
         let result = serde_json::from_str::<DartPackageResponse>(&body)?;
-        let is_plugin = match result.latest.pubspec.flutter {
-            Some(value) => value.as_object().iter().any(|e| e.contains_key("plugin")),
-            None => false,
+        let pubspec = match lock {
+            Some(lock) => result
+                .versions
+                .iter()
+                .find(|e| e.pubspec.version == lock.version)
+                .map(|e| e.pubspec.clone())
+                .unwrap_or(result.latest.pubspec.clone()),
+            None => result.latest.pubspec.clone(),
+        };
+        let flutter = pubspec.flutter.unwrap_or_default();
+        let is_plugin = flutter.as_object().iter().any(|e| e.contains_key("plugin"));
+        let (platforms, is_native) = PubspecModel::get_platforms(is_plugin, &flutter["plugin"]["platforms"]);
+        // License from tags score: None - request failed, Some(None) - license not found
+        let url_score = format!("https://pub.dev/api/packages/{package_name}/score");
+        let license = match client.get_request(url_score) {
+            Ok(response) => match response.text().await {
+                Ok(body) => serde_json::from_str::<DartPackageScoreResponse>(&body)
+                    .ok()
+                    .map(|e| license_utils::license_from_tags(&e.tags)),
+                Err(_) => None,
+            },
+            Err(_) => None,
+        };
+        let license_status = if license.is_none() {
+            Some(LicenseStatus::Failed)
+        } else {
+            None
+        };
+        // Sdk dependency not on pub.dev: flutter_web_plugins
+        let dependencies = match pubspec.dependencies {
+            Some(value) => value
+                .as_object()
+                .map(|e| {
+                    e.iter()
+                        .filter(|(_, value)| value.get("sdk").is_none())
+                        .map(|(key, _)| key.clone())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            None => vec![],
         };
         return Ok((
             PubspecModel {
                 name: result.name,
-                description: result.latest.pubspec.description,
-                repository: result.latest.pubspec.repository,
+                description: pubspec.description,
+                repository: pubspec.repository,
                 version: result.latest.pubspec.version,
                 pub_dev: utils::check_url(url_pub),
                 is_plugin,
+                is_native,
+                platforms,
+                aurora: None,
+                version_lock: lock.map(|e| e.version.clone()),
+                source: lock.map(|e| e.source.clone()),
+                dependencies: vec![],
+                license: license.flatten(),
+                license_status,
                 level,
             },
-            result.latest.pubspec.dependencies,
+            dependencies,
         ));
     }
 }
@@ -1,9 +1,12 @@
 use serde::Deserialize;
+use serde::Serialize;
 
 #[derive(Deserialize, Clone, Debug)]
 pub struct DartPackageResponse {
     pub name: String,
     pub latest: DartPackageVersionResponse,
+    #[serde(default)]
+    pub versions: Vec<DartPackageVersionResponse>,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -19,3 +22,19 @@ pub struct DartPackagePubspecResponse {
     pub flutter: Option<serde_json::Value>,
     pub dependencies: Option<serde_json::Value>,
 }
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct DartPackageScoreResponse {
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Version package with archive, save to mirror
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DartPackageArchiveResponse {
+    pub version: String,
+    pub archive_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_sha256: Option<String>,
+    pub pubspec: serde_json::Value,
+}
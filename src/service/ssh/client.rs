@@ -169,6 +169,66 @@ impl SshSession {
         Ok(response)
     }
 
+    pub fn call_callback<T: FnMut(String)>(
+        &self,
+        command: &str,
+        callback: T,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        tokio::task::block_in_place(|| Handle::current().block_on(self._call_callback(command, callback)))
+    }
+
+    /// Exec with line output stdout & stderr, error if exit status not 0
+    async fn _call_callback<T: FnMut(String)>(
+        &self,
+        command: &str,
+        mut callback: T,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut code = None;
+        // Data split by chunks, line and utf-8 char can be between them
+        let mut buffer: Vec<u8> = vec![];
+        let mut buffer_ext: Vec<u8> = vec![];
+        let mut channel = self.session.channel_open_session().await?;
+        channel.exec(true, command).await?;
+        loop {
+            let Some(msg) = channel.wait().await else {
+                break;
+            };
+            match msg {
+                ChannelMsg::Data { ref data } => Self::_callback_lines(&mut buffer, data, &mut callback),
+                ChannelMsg::ExtendedData { ref data, .. } => {
+                    Self::_callback_lines(&mut buffer_ext, data, &mut callback)
+                }
+                ChannelMsg::ExitStatus { exit_status } => {
+                    code = Some(exit_status);
+                }
+                _ => {}
+            }
+        }
+        // Flush last line without new line
+        Self::_callback_line(&buffer, &mut callback);
+        Self::_callback_line(&buffer_ext, &mut callback);
+        if code.is_some_and(|e| e != 0) {
+            Err(tr!("произошла ошибка при выполнении команды"))?
+        }
+        Ok(())
+    }
+
+    /// Send complete lines from buffer, rest wait next data
+    fn _callback_lines<T: FnMut(String)>(buffer: &mut Vec<u8>, data: &[u8], callback: &mut T) {
+        buffer.extend_from_slice(data);
+        while let Some(index) = buffer.iter().position(|e| *e == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=index).collect();
+            Self::_callback_line(&line, callback);
+        }
+    }
+
+    fn _callback_line<T: FnMut(String)>(line: &[u8], callback: &mut T) {
+        let line = String::from_utf8_lossy(line);
+        if !line.trim().is_empty() {
+            callback(line.trim_end().to_string());
+        }
+    }
+
     pub fn run(&self, command: &str) -> Result<(), Box<dyn std::error::Error>> {
         tokio::task::block_in_place(|| Handle::current().block_on(self._run(command)))
     }
@@ -14,6 +14,8 @@ use crate::tools::macros::crash;
 use crate::tools::macros::print_error;
 use crate::tools::macros::print_info;
 use crate::tools::macros::print_success;
+use crate::tools::macros::print_warning;
+use crate::tools::macros::tr;
 use crate::tools::single;
 use clap::Args;
 use clap::Subcommand;
@@ -42,10 +44,6 @@ pub struct SvcArgs {
     #[arg(short, long, default_value_t = false)]
     logout: bool,
 
-    /// Обновить sudoers для Platform SDK
-    #[arg(short, long, default_value_t = false)]
-    permissions: bool,
-
     /// Поиск и синхронизация
     #[command(subcommand)]
     sync: Option<SyncCommands>,
@@ -60,6 +58,30 @@ pub enum SyncCommands {
     /// Синхронизация данных
     #[command(short_flag = 's')]
     Sync(SyncArgs),
+    /// Записи sudoers для Platform SDK
+    #[command(short_flag = 'p', long_flag = "permissions")]
+    Permissions(PermissionsArgs),
+}
+
+#[derive(Args)]
+#[command()]
+#[group(multiple = false)]
+pub struct PermissionsArgs {
+    /// Показать записи sudoers и установленные Platform SDK
+    #[arg(short, long, default_value_t = false)]
+    show: bool,
+
+    /// Удалить записи sudoers для отсутствующих Platform SDK
+    #[arg(short, long, default_value_t = false)]
+    clean: bool,
+
+    /// Удалить все записи sudoers
+    #[arg(short, long, default_value_t = false)]
+    remove: bool,
+
+    /// Показать это сообщение и выйти
+    #[clap(short='h', long, action = clap::ArgAction::HelpLong)]
+    help: Option<bool>,
 }
 
 #[derive(Args)]
@@ -123,19 +145,6 @@ pub fn run(arg: SvcArgs) {
         AppAuthLogoutIncoming::new().run(OutgoingType::Cli).print();
         return;
     }
-    if arg.permissions {
-        print_info!("поиск Platform SDK в системе");
-        let models = match PsdkInstalledModel::search_full_without_targets() {
-            Ok(value) => value,
-            Err(error) => crash!(error),
-        };
-        print_info!("обновление записи sudoers");
-        match command::psdk::add_sudoers_chroot_access(&models) {
-            Ok(_) => print_success!("запись sudoers успешно обновлена"),
-            Err(error) => print_error!(error),
-        }
-        return;
-    }
     if let Some(token) = arg.auth {
         AppAuthLoginIncoming::new(token).run(OutgoingType::Cli).print();
         return;
@@ -159,6 +168,77 @@ pub fn run(arg: SvcArgs) {
                     SdkSyncIncoming::new().run(OutgoingType::Cli).print();
                 }
             }
+            SyncCommands::Permissions(arg) => {
+                if arg.show {
+                    permissions_show();
+                    return;
+                }
+                if arg.remove {
+                    print_info!("удаление записи sudoers");
+                    match command::psdk::remove_sudoers_chroot_access() {
+                        Ok(_) => print_success!("запись sudoers успешно удалена"),
+                        Err(error) => print_error!(error),
+                    }
+                    return;
+                }
+                print_info!("поиск Platform SDK в системе");
+                let models = match PsdkInstalledModel::search_full_without_targets() {
+                    Ok(value) => value,
+                    Err(error) => crash!(error),
+                };
+                if arg.clean {
+                    let dirs = models.iter().map(|e| e.dir.clone()).collect::<Vec<String>>();
+                    let stale = match command::psdk::get_sudoers_chroot_access() {
+                        Ok(value) => value.into_iter().filter(|e| !dirs.contains(e)).collect::<Vec<String>>(),
+                        Err(error) => crash!(error),
+                    };
+                    if stale.is_empty() {
+                        print_info!("устаревшие записи sudoers не найдены");
+                        return;
+                    }
+                    for dir in stale {
+                        let message = tr!("устаревшая запись sudoers: {}", dir);
+                        print_warning!(message);
+                    }
+                }
+                print_info!("обновление записи sudoers");
+                match command::psdk::add_sudoers_chroot_access(&models) {
+                    Ok(_) => print_success!("запись sudoers успешно обновлена"),
+                    Err(error) => print_error!(error),
+                }
+            }
         }
     }
 }
+
+/// Print sudoers records against installed Platform SDK
+fn permissions_show() {
+    print_info!("поиск Platform SDK в системе");
+    let models = match PsdkInstalledModel::search_full_without_targets() {
+        Ok(value) => value,
+        Err(error) => crash!(error),
+    };
+    let dirs = match command::psdk::get_sudoers_chroot_access() {
+        Ok(value) => value,
+        Err(error) => crash!(error),
+    };
+    for model in &models {
+        if !dirs.contains(&model.dir) {
+            let message = tr!("Platform SDK {}: нет записи: {}", model.version_id, model.dir);
+            print_warning!(message);
+        } else if command::psdk::is_sudoers_chroot_access(model) {
+            let message = tr!("Platform SDK {}: доступ есть: {}", model.version_id, model.dir);
+            print_info!(message);
+        } else {
+            let message = tr!("Platform SDK {}: нет доступа: {}", model.version_id, model.dir);
+            print_warning!(message);
+        }
+    }
+    for dir in dirs.iter().filter(|e| !models.iter().any(|m| &&m.dir == e)) {
+        let message = tr!("устаревшая запись: {}", dir);
+        print_warning!(message);
+    }
+    if models.is_empty() && dirs.is_empty() {
+        print_info!("записи sudoers и Platform SDK не найдены");
+    }
+}
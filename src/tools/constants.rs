@@ -30,6 +30,15 @@ pub const CONFIGURATION_FILE: &str = "aurora-bot.configuration";
 /// File name for save devices configuration
 pub const DEVICES_CONFIGURATION_FILE: &str = "devices.json";
 
+/// File name for pin version Flutter SDK in project
+pub const FLUTTER_VERSION_FILE: &str = ".flutter-aurora-version";
+
+/// Dir name for offline mirror pub packages
+pub const PUB_MIRROR_DIR: &str = "pub-mirror";
+
+/// Port local server mirror pub packages
+pub const PUB_MIRROR_PORT: u16 = 8090;
+
 /// File name for save environment bash
 pub const ENVIRONMENT_FILE: &str = "aurora-bot.environment";
 
@@ -48,7 +57,7 @@ pub const VERSION_APP: &str = "0.1.8";
 pub const VERSION_API: &str = "0.1.0";
 
 /// Version configuration
-pub const VERSION_CONFIGURATION: &str = "1";
+pub const VERSION_CONFIGURATION: &str = "2";
 
 /// Debug log json
 #[cfg(debug_assertions)]
@@ -1,26 +1,214 @@
+use std::env;
 use std::fs::File;
 use std::fs::{self};
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 
 use clang_format::ClangFormatStyle;
 use clang_format::clang_format_with_style;
+use colored::Colorize;
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
 use walkdir::WalkDir;
+use yaml_rust::YamlLoader;
 
 use crate::service::command::exec;
 
+use super::macros::print_error;
+use super::macros::print_success;
+use super::macros::print_warning;
 use super::macros::tr;
 use super::programs;
 use super::utils;
 
+/// Write - format files, Check - only search files need format, Diff - check with unified diff
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum FormatMode {
+    #[default]
+    Write,
+    Check,
+    Diff,
+}
+
+impl FormatMode {
+    pub fn from_name(name: &str) -> Option<FormatMode> {
+        match name.to_lowercase().as_str() {
+            "write" => Some(FormatMode::Write),
+            "check" => Some(FormatMode::Check),
+            "diff" => Some(FormatMode::Diff),
+            _ => None,
+        }
+    }
+}
+
+/// File need format, path relative project
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FormatFile {
+    pub path: String,
+    pub diff: Option<String>,
+}
+
 pub struct FormatResult {
     pub count_files: usize,
     pub count_exclude: usize,
     pub count_formats: usize,
+    pub files: Vec<FormatFile>,
+}
+
+impl FormatResult {
+    pub fn empty() -> FormatResult {
+        FormatResult {
+            count_files: 0,
+            count_exclude: 0,
+            count_formats: 0,
+            files: vec![],
+        }
+    }
+
+    pub fn append(&mut self, mut other: FormatResult) {
+        self.count_files += other.count_files;
+        self.count_exclude += other.count_exclude;
+        self.count_formats += other.count_formats;
+        self.files.append(&mut other.files);
+    }
+}
+
+/// Print files need format with colored diff
+pub fn print_files(files: &[FormatFile], count_files: usize, count_formats: usize) {
+    for file in files {
+        match &file.diff {
+            Some(diff) => {
+                for line in diff.lines() {
+                    if line.starts_with("+++") || line.starts_with("---") {
+                        println!("{}", line.bold().white());
+                    } else if line.starts_with('+') {
+                        println!("{}", line.green());
+                    } else if line.starts_with('-') {
+                        println!("{}", line.red());
+                    } else if line.starts_with("@@") {
+                        println!("{}", line.cyan());
+                    } else {
+                        println!("{}", line);
+                    }
+                }
+            }
+            None => {
+                let message = tr!("требуется форматирование: {}", file.path);
+                print_warning!(message);
+            }
+        }
+    }
+    if count_formats == 0 {
+        let message = tr!("проект не требует форматирования");
+        print_success!(message);
+    } else {
+        let message = tr!("найдено: {}, требуют форматирования: {}", count_files, count_formats);
+        print_error!(message);
+    }
+}
+
+/// Config format in root project
+pub const FORMAT_CONFIG: &str = ".aurora-format.yaml";
+
+/// Languages for format and exclude patterns: `*` - part name, `**` - any path
+///
+/// ```yaml
+/// languages:
+///   cpp: true
+///   qml: true
+///   cmake: false
+///   dart: true
+/// exclude:
+///   - 3rdparty
+///   - src/generated/*.cpp
+/// ```
+pub struct FormatConfig {
+    pub cpp: bool,
+    pub qml: bool,
+    pub cmake: bool,
+    pub dart: bool,
+    pub exclude: Vec<Regex>,
+}
+
+impl FormatConfig {
+    /// Load config project, without file all languages enabled and excluded 3rdparty
+    pub fn load(path: &Path) -> Result<FormatConfig, Box<dyn std::error::Error>> {
+        Self::load_defaults(path, true)
+    }
+
+    /// Load config Flutter project, CMake format only if enabled in config
+    pub fn load_flutter(path: &Path) -> Result<FormatConfig, Box<dyn std::error::Error>> {
+        Self::load_defaults(path, false)
+    }
+
+    fn load_defaults(path: &Path, cmake: bool) -> Result<FormatConfig, Box<dyn std::error::Error>> {
+        let mut config = FormatConfig {
+            cpp: true,
+            qml: true,
+            cmake,
+            dart: true,
+            exclude: vec![Self::pattern("3rdparty")?],
+        };
+        let file = path.join(FORMAT_CONFIG);
+        if !file.exists() {
+            return Ok(config);
+        }
+        let docs = match YamlLoader::load_from_str(&fs::read_to_string(&file)?) {
+            Ok(value) => value,
+            Err(_) => Err(tr!("не удалось прочитать {}", FORMAT_CONFIG))?,
+        };
+        let Some(doc) = docs.first() else {
+            return Ok(config);
+        };
+        let languages = &doc["languages"];
+        config.cpp = languages["cpp"].as_bool().unwrap_or(config.cpp);
+        config.qml = languages["qml"].as_bool().unwrap_or(config.qml);
+        config.cmake = languages["cmake"].as_bool().unwrap_or(config.cmake);
+        config.dart = languages["dart"].as_bool().unwrap_or(config.dart);
+        if let Some(exclude) = doc["exclude"].as_vec() {
+            config.exclude = exclude
+                .iter()
+                .filter_map(|e| e.as_str())
+                .map(Self::pattern)
+                .collect::<Result<Vec<Regex>, regex::Error>>()?;
+        }
+        Ok(config)
+    }
+
+    /// Check path relative project by exclude patterns
+    pub fn is_exclude(&self, name: &str) -> bool {
+        self.exclude.iter().any(|e| e.is_match(name))
+    }
+
+    /// Pattern to regex, matches any part of path
+    fn pattern(pattern: &str) -> Result<Regex, regex::Error> {
+        let mut result = String::new();
+        let mut chars = pattern.trim_matches('/').chars().peekable();
+        while let Some(char) = chars.next() {
+            match char {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    result.push_str(".*");
+                }
+                '*' => result.push_str("[^/]*"),
+                '?' => result.push_str("[^/]"),
+                _ => result.push_str(&regex::escape(&char.to_string())),
+            }
+        }
+        Regex::new(&format!("(^|/){}($|/)", result))
+    }
 }
 
 /// Format Dart project
-pub fn dart_format(path: &PathBuf, dart: &PathBuf) -> Result<FormatResult, Box<dyn std::error::Error>> {
+pub fn dart_format(
+    path: &PathBuf,
+    dart: &PathBuf,
+    mode: &FormatMode,
+    config: &FormatConfig,
+) -> Result<FormatResult, Box<dyn std::error::Error>> {
     // Check folder is dir
     if !path.is_dir() {
         Err(tr!("укажите директорию проекта"))?
@@ -29,76 +217,239 @@ pub fn dart_format(path: &PathBuf, dart: &PathBuf) -> Result<FormatResult, Box<d
         Err(tr!("укажите путь к Dart"))?
     }
     // Search files for format
-    let files = search_files(path, [".dart"]);
+    let (files, count_exclude) = search_files_config(path, [".dart"], config);
+    if files.is_empty() {
+        return Ok(FormatResult {
+            count_exclude,
+            ..FormatResult::empty()
+        });
+    }
+    let mut args = vec!["format".to_string(), "--line-length=120".to_string()];
+    if *mode != FormatMode::Write {
+        args.push("--output=json".to_string());
+    }
+    args.extend(files.iter().map(|e| e.to_string_lossy().to_string()));
+    let output = exec::exec_wait_args(&dart.to_string_lossy(), args)?;
 
     // Format
-    let output = exec::exec_wait_args(
-        &dart.to_string_lossy(),
-        ["format", "--line-length=120", &path.to_string_lossy()],
-    )?;
-    let lines = utils::parse_output(output.stdout);
+    if *mode == FormatMode::Write {
+        let lines = utils::parse_output(output.stdout);
+        // Formatted lib/main.dart
+        let formats = lines[..lines.len().saturating_sub(1)]
+            .iter()
+            .filter_map(|e| e.strip_prefix("Formatted "))
+            .map(|e| FormatFile {
+                path: Path::new(e)
+                    .strip_prefix(path)
+                    .unwrap_or(Path::new(e))
+                    .to_string_lossy()
+                    .to_string(),
+                diff: None,
+            })
+            .collect::<Vec<FormatFile>>();
+        return Ok(FormatResult {
+            count_files: files.len() + count_exclude,
+            count_exclude,
+            count_formats: formats.len(),
+            files: formats,
+        });
+    }
+    // Check without write: {"path": "...", "source": "...", "selection": {...}}
+    let mut formats: Vec<FormatFile> = vec![];
+    for line in utils::parse_output(output.stdout) {
+        let value = match serde_json::from_str::<Value>(&line) {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+        let (Some(file_path), Some(source)) = (value["path"].as_str(), value["source"].as_str()) else {
+            continue;
+        };
+        let file_path = path.join(file_path);
+        let content = fs::read_to_string(&file_path)?;
+        if content != source {
+            formats.push(get_format_file(path, &file_path, source, mode)?);
+        }
+    }
     Ok(FormatResult {
-        count_files: files.len(),
-        count_exclude: 0,
-        count_formats: lines.len() - 1,
+        count_files: files.len() + count_exclude,
+        count_exclude,
+        count_formats: formats.len(),
+        files: formats,
     })
 }
 
 /// Format C++ project
-pub fn cpp_format(path: &PathBuf) -> Result<FormatResult, Box<dyn std::error::Error>> {
+pub fn cpp_format(
+    path: &PathBuf,
+    mode: &FormatMode,
+    config: &FormatConfig,
+) -> Result<FormatResult, Box<dyn std::error::Error>> {
     // Check exist dependency
     let _ = programs::get_clang_format()?;
     // Check folder is dir
     if !path.is_dir() {
         Err(tr!("укажите директорию проекта"))?
     }
+    // Style
+    let style = if path.join(".clang-format").exists() {
+        let format_path = path.join(".clang-format");
+        ClangFormatStyle::Custom(format!("file:{}", format_path.to_string_lossy()))
+    } else {
+        ClangFormatStyle::Custom("{ BasedOnStyle: Chromium, ReflowComments: false, ColumnLimit: 120 }".to_string())
+    };
+    // Format
+    format_files(path, [".h", ".hpp", ".cpp"], mode, config, |_, content| {
+        Ok(clang_format_with_style(content, &style)?)
+    })
+}
+
+/// Format QML and JavaScript by qmlformat
+pub fn qml_format(
+    path: &PathBuf,
+    qmlformat: &str,
+    mode: &FormatMode,
+    config: &FormatConfig,
+) -> Result<FormatResult, Box<dyn std::error::Error>> {
+    // Check folder is dir
+    if !path.is_dir() {
+        Err(tr!("укажите директорию проекта"))?
+    }
+    // Format
+    format_files(path, [".qml", ".js"], mode, config, |file_path, _| {
+        format_stdout(qmlformat, file_path)
+    })
+}
+
+/// Format CMakeLists.txt and *.cmake by cmake-format or gersemi
+pub fn cmake_format(
+    path: &PathBuf,
+    mode: &FormatMode,
+    config: &FormatConfig,
+) -> Result<FormatResult, Box<dyn std::error::Error>> {
+    // Check exist dependency
+    let program = programs::get_cmake_format()?;
+    // Check folder is dir
+    if !path.is_dir() {
+        Err(tr!("укажите директорию проекта"))?
+    }
+    // Format
+    format_files(path, ["CMakeLists.txt", ".cmake"], mode, config, |file_path, _| {
+        format_stdout(&program, file_path)
+    })
+}
+
+/// Formatter print result to stdout
+fn format_stdout(program: &str, file_path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+    let output = exec::exec_wait_args(program, [file_path])?;
+    if !output.status.success() {
+        let name = file_path.to_string_lossy();
+        Err(tr!("не удалось форматировать файл: {}", name))?
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Format files by formatter, save result in mode Write
+fn format_files<'s, F>(
+    path: &PathBuf,
+    extensions: impl IntoIterator<Item = &'s str>,
+    mode: &FormatMode,
+    config: &FormatConfig,
+    formatter: F,
+) -> Result<FormatResult, Box<dyn std::error::Error>>
+where
+    F: Fn(&Path, &str) -> Result<String, Box<dyn std::error::Error>>,
+{
     // Search files for format
-    let files = search_files(path, [".h", ".hpp", ".cpp"]);
+    let (files, count_exclude) = search_files_config(path, extensions, config);
     // Format
-    let mut count_exclude = 0;
-    let mut count_formats = 0;
+    let mut formats: Vec<FormatFile> = vec![];
     for file_path in &files {
-        // Filter 3rdparty
-        if file_path.to_string_lossy().contains("3rdparty") {
-            count_exclude += 1;
-            continue;
-        }
         // Get content
-        let content = fs::read_to_string(&file_path)?;
+        let content = fs::read_to_string(file_path)?;
         // Format
-        let output = if path.join(".clang-format").exists() {
-            let format_path = path.join(".clang-format");
-            let format_path = format_path.to_string_lossy();
-            clang_format_with_style(&content, &ClangFormatStyle::Custom(format!("file:{}", format_path)))?
-        } else {
-            clang_format_with_style(
-                &content,
-                &ClangFormatStyle::Custom(
-                    "{ BasedOnStyle: Chromium, ReflowComments: false, ColumnLimit: 120 }".to_string(),
-                ),
-            )?
-        };
+        let output = formatter(file_path, &content)?;
+        if content == output {
+            continue;
+        }
         // Save format to file
-        if content != output {
-            let mut file = File::create(&file_path)?;
+        if *mode == FormatMode::Write {
+            let mut file = File::create(file_path)?;
             file.write_all(output.as_bytes())?;
-            count_formats += 1;
         }
+        formats.push(get_format_file(path, file_path, &output, mode)?);
     }
     Ok(FormatResult {
-        count_files: files.len(),
+        count_files: files.len() + count_exclude,
         count_exclude,
-        count_formats,
+        count_formats: formats.len(),
+        files: formats,
     })
 }
 
-/// Search files
+/// File need format with diff for mode Diff
+fn get_format_file(
+    path: &Path,
+    file_path: &Path,
+    output: &str,
+    mode: &FormatMode,
+) -> Result<FormatFile, Box<dyn std::error::Error>> {
+    let name = file_path
+        .strip_prefix(path)
+        .unwrap_or(file_path)
+        .to_string_lossy()
+        .to_string();
+    let diff = if *mode == FormatMode::Diff {
+        Some(get_diff(file_path, &name, output)?)
+    } else {
+        None
+    };
+    Ok(FormatFile { path: name, diff })
+}
+
+/// Unified diff file with formatted content
+fn get_diff(file_path: &Path, name: &str, output: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let program = programs::get_diff()?;
+    let temp = env::temp_dir().join(format!(
+        "aurora-bot-format-{:x}",
+        md5::compute(file_path.to_string_lossy().as_bytes())
+    ));
+    fs::write(&temp, output)?;
+    let result = exec::exec_wait_args(
+        &program,
+        [
+            "-u",
+            "--label",
+            &format!("a/{name}"),
+            "--label",
+            &format!("b/{name}"),
+            &file_path.to_string_lossy(),
+            &temp.to_string_lossy(),
+        ],
+    );
+    let _ = fs::remove_file(&temp);
+    Ok(String::from_utf8_lossy(&result?.stdout).to_string())
+}
+
+/// Search files without excluded, return files and count excluded
+fn search_files_config<'s>(
+    path: &PathBuf,
+    extensions: impl IntoIterator<Item = &'s str>,
+    config: &FormatConfig,
+) -> (Vec<PathBuf>, usize) {
+    let (exclude, files): (Vec<PathBuf>, Vec<PathBuf>) = search_files(path, extensions)
+        .into_iter()
+        .partition(|e| config.is_exclude(&e.strip_prefix(path).unwrap_or(e).to_string_lossy()));
+    (files, exclude.len())
+}
+
+/// Search files, skip hidden directories
 fn search_files<'s>(path: &PathBuf, extensions: impl IntoIterator<Item = &'s str>) -> Vec<PathBuf> {
     let extensions = extensions.into_iter().map(|e| e.to_string()).collect::<Vec<String>>();
     let mut result: Vec<PathBuf> = vec![];
     for entry in WalkDir::new(path)
         .follow_links(false)
         .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !e.file_name().to_string_lossy().starts_with('.'))
         .filter_map(|e| e.ok())
     {
         for extension in &extensions {
@@ -1,11 +1,29 @@
+use std::path::Path;
 use std::path::PathBuf;
 
 use genpdf::Alignment;
 use genpdf::Element as _;
 use genpdf::elements;
+use genpdf::fonts::FontData;
+use genpdf::fonts::FontFamily;
 use genpdf::style;
+use walkdir::WalkDir;
 
+use crate::models::pubspec::model::PubspecAurora;
 use crate::models::pubspec::model::PubspecModel;
+use crate::tools::macros::tr;
+use crate::tools::utils;
+
+/// Directories for search fonts
+const FONT_DIRS: [&str; 3] = ["/usr/share/fonts", "/usr/local/share/fonts", ".local/share/fonts"];
+
+/// Fonts with Cyrillic: name and suffixes regular, bold, italic, bold italic
+const FONT_FAMILIES: [(&str, [&str; 4]); 4] = [
+    ("LiberationSans", ["-Regular", "-Bold", "-Italic", "-BoldItalic"]),
+    ("DejaVuSans", ["", "-Bold", "-Oblique", "-BoldOblique"]),
+    ("NotoSans", ["-Regular", "-Bold", "-Italic", "-BoldItalic"]),
+    ("PTSans", ["-Regular", "-Bold", "-Italic", "-BoldItalic"]),
+];
 
 /// Generate report about dart packager in Flutter project
 pub fn gen_report_dart_packages(
@@ -18,11 +36,9 @@ pub fn gen_report_dart_packages(
     packages.sort_by_key(|e| e.level);
     let mut plugins: Vec<PubspecModel> = dependencies.iter().filter(|e| e.is_plugin).cloned().collect();
     plugins.sort_by_key(|e| e.level);
+    let licenses: Vec<PubspecModel> = packages.iter().chain(plugins.iter()).cloned().collect();
     // Base configuration file
-    let font_family = match genpdf::fonts::from_files("/usr/share/fonts/liberation", "LiberationSans", None) {
-        Ok(value) => value,
-        Err(_) => genpdf::fonts::from_files("/usr/share/fonts/truetype/liberation", "LiberationSans", None)?,
-    };
+    let font_family = _get_font_family()?;
     let mut doc = genpdf::Document::new(font_family);
     doc.set_title("Отчет pubspec.yaml");
     let mut decorator = genpdf::SimplePageDecorator::new();
@@ -42,6 +58,36 @@ pub fn gen_report_dart_packages(
             .styled_string(&project.name, style::Style::from(style::Color::Rgb(0, 87, 155)).bold())
             .string(". Пакеты разделены на платформа не зависимые и платформа зависимые (плагины). Такой отчет упрощает процесс портирование приложения Flutter под платформу ОС Аврора.")
     );
+    // Aurora OS compatibility
+    if !plugins.is_empty() {
+        let count = |aurora: PubspecAurora| plugins.iter().filter(|e| e.aurora == Some(aurora.clone())).count();
+        doc.push(elements::Break::new(1.5));
+        doc.push(
+            elements::Paragraph::new("Совместимость с ОС Аврора")
+                .aligned(Alignment::Center)
+                .styled(style::Style::new().bold().with_font_size(16)),
+        );
+        doc.push(elements::Break::new(1.0));
+        let mut list = elements::UnorderedList::new();
+        list.push(elements::Paragraph::new(format!(
+            "Поддерживается: {}",
+            count(PubspecAurora::Supported)
+        )));
+        list.push(elements::Paragraph::new(format!(
+            "Требуется портирование: {}",
+            count(PubspecAurora::Port)
+        )));
+        list.push(elements::Paragraph::new(format!(
+            "Не зависит от платформы: {}",
+            count(PubspecAurora::Independent)
+        )));
+        list.push(
+            elements::Paragraph::default()
+                .string("Трудоемкость портирования: ")
+                .styled_string(PubspecModel::get_aurora_effort(&plugins), style::Style::new().bold()),
+        );
+        doc.push(list);
+    }
     // Simple package
     if packages.len() != 0 {
         doc.push(elements::Break::new(1.5));
@@ -96,11 +142,54 @@ pub fn gen_report_dart_packages(
         }
         doc.push(table);
     }
+    // Licenses
+    if !licenses.is_empty() {
+        let violation_color = style::Color::Rgb(192, 57, 43);
+        doc.push(elements::Break::new(1.5));
+        doc.push(
+            elements::Paragraph::new("Лицензии")
+                .aligned(Alignment::Center)
+                .styled(style::Style::new().bold().with_font_size(16)),
+        );
+        let count_violations = licenses.iter().filter(|e| e.is_license_violation()).count();
+        if count_violations != 0 {
+            doc.push(
+                elements::Paragraph::new(format!("(нарушения: {})", count_violations))
+                    .aligned(Alignment::Center)
+                    .styled(violation_color),
+            );
+        }
+        doc.push(elements::Break::new(1.5));
+        let mut table = elements::TableLayout::new(vec![2, 1, 1]);
+        table.set_cell_decorator(elements::FrameCellDecorator::new(true, true, false));
+        for item in licenses {
+            let item_style = match item.is_license_violation() {
+                true => style::Style::from(violation_color),
+                false => style::Style::new(),
+            };
+            table
+                .row()
+                .element(elements::Paragraph::new(&item.name).padded(1))
+                .element(
+                    elements::Paragraph::new(item.license.clone().unwrap_or("-".to_string()))
+                        .styled(item_style)
+                        .padded(1),
+                )
+                .element(
+                    elements::Paragraph::new(item.license_status.map(|e| e.to_name()).unwrap_or_default())
+                        .styled(item_style)
+                        .padded(1),
+                )
+                .push()
+                .expect("Invalid table row");
+        }
+        doc.push(table);
+    }
     // Footer
     doc.push(elements::Break::new(0.5));
     doc.push(
         elements::Paragraph::default().styled_string(
-            "*Отчёт генерируется на основе версий актуальных пакетов.",
+            "*Отчёт генерируется на основе версий из pubspec.lock или актуальных пакетов.",
             style::Style::from(style::Color::Rgb(109, 109, 109))
                 .italic()
                 .with_font_size(10),
@@ -132,6 +221,21 @@ fn _gen_layout_dart_package(package: &PubspecModel) -> elements::LinearLayout {
     layout.push(elements::Break::new(0.2));
     // Info list
     let mut list = elements::UnorderedList::new();
+    if let Some(version_lock) = &package.version_lock {
+        let (lock_color, outdated) = match package.is_outdated() {
+            true => (style::Color::Rgb(192, 57, 43), " (устарел)"),
+            false => (info_color, ""),
+        };
+        let source = match package.source.as_deref() {
+            Some("hosted") | None => String::new(),
+            Some(source) => format!(" ({})", source),
+        };
+        list.push(
+            elements::Paragraph::default()
+                .styled_string(format!("Pinned: v{}{}{}", version_lock, source, outdated), lock_color)
+                .styled(style::Style::new().with_font_size(10)),
+        );
+    }
     list.push(
         elements::Paragraph::default()
             .styled_string(format!("Latest: v{}", &package.version), info_color)
@@ -142,6 +246,18 @@ fn _gen_layout_dart_package(package: &PubspecModel) -> elements::LinearLayout {
             .styled_string(format!("Level: {}", &package.level), info_color)
             .styled(style::Style::new().with_font_size(10)),
     );
+    if let Some(aurora) = &package.aurora {
+        let aurora_color = match aurora {
+            PubspecAurora::Supported => style::Color::Rgb(30, 130, 76),
+            PubspecAurora::Port => style::Color::Rgb(192, 57, 43),
+            PubspecAurora::Independent => info_color,
+        };
+        list.push(
+            elements::Paragraph::default()
+                .styled_string(format!("Aurora: {}", aurora.to_name()), aurora_color)
+                .styled(style::Style::new().with_font_size(10)),
+        );
+    }
     layout.push(list);
     // Desc
     if let Some(description) = &package.description {
@@ -172,3 +288,40 @@ fn _gen_layout_dart_package(package: &PubspecModel) -> elements::LinearLayout {
     // Result
     layout
 }
+
+/// Search font family with Cyrillic, missing styles replaced by regular
+fn _get_font_family() -> Result<FontFamily<FontData>, Box<dyn std::error::Error>> {
+    let fonts: Vec<PathBuf> = FONT_DIRS
+        .iter()
+        // Absolute path replace home
+        .map(|e| utils::get_home_folder_path().join(e))
+        .filter(|e| e.exists())
+        .flat_map(|e| WalkDir::new(e).into_iter().filter_map(|e| e.ok()))
+        .map(|e| e.into_path())
+        .filter(|e| e.extension().is_some_and(|e| e == "ttf"))
+        .collect();
+    let find = |name: String| {
+        fonts
+            .iter()
+            .find(|e| e.file_stem().is_some_and(|e| e.to_string_lossy() == name))
+    };
+    for (name, suffixes) in FONT_FAMILIES {
+        let Some(regular) = find(format!("{}{}", name, suffixes[0])) else {
+            continue;
+        };
+        let load = |path: &Path| FontData::load(path, None);
+        let load_or_regular = |suffix: &str| match find(format!("{}{}", name, suffix)) {
+            Some(path) => load(path),
+            None => load(regular),
+        };
+        return Ok(FontFamily {
+            regular: load(regular)?,
+            bold: load_or_regular(suffixes[1])?,
+            italic: load_or_regular(suffixes[2])?,
+            bold_italic: load_or_regular(suffixes[3])?,
+        });
+    }
+    Err(tr!(
+        "не найден шрифт с поддержкой кириллицы: Liberation Sans, DejaVu Sans, Noto Sans или PT Sans"
+    ))?
+}
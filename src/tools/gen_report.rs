@@ -0,0 +1,450 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::models::pubspec::model::PubspecAurora;
+use crate::models::pubspec::model::PubspecModel;
+
+use super::gen_pdf;
+
+/// Pdf - document, Json - for tooling, Markdown - for merge request, Html - standalone page,
+/// Dot and Mermaid - dependency graph
+#[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum ReportFormat {
+    #[default]
+    Pdf,
+    Json,
+    Markdown,
+    Html,
+    Dot,
+    Mermaid,
+}
+
+impl ReportFormat {
+    pub fn from_name(name: &str) -> Option<ReportFormat> {
+        match name.to_lowercase().as_str() {
+            "pdf" => Some(ReportFormat::Pdf),
+            "json" => Some(ReportFormat::Json),
+            "md" | "markdown" => Some(ReportFormat::Markdown),
+            "html" => Some(ReportFormat::Html),
+            "dot" => Some(ReportFormat::Dot),
+            "mmd" | "mermaid" => Some(ReportFormat::Mermaid),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &str {
+        match self {
+            ReportFormat::Pdf => "pdf",
+            ReportFormat::Json => "json",
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+            ReportFormat::Dot => "dot",
+            ReportFormat::Mermaid => "mmd",
+        }
+    }
+}
+
+/// Report about dart packages for JSON
+#[derive(Serialize)]
+struct DartPackagesReport {
+    project: PubspecModel,
+    date: String,
+    count_supported: usize,
+    count_port: usize,
+    count_independent: usize,
+    count_outdated: usize,
+    count_license_violations: usize,
+    effort: String,
+    packages: Vec<PubspecModel>,
+    plugins: Vec<PubspecModel>,
+}
+
+impl DartPackagesReport {
+    fn new(project: PubspecModel, dependencies: Vec<PubspecModel>) -> DartPackagesReport {
+        // Filter and sort by level
+        let mut packages: Vec<PubspecModel> = dependencies.iter().filter(|e| !e.is_plugin).cloned().collect();
+        packages.sort_by_key(|e| e.level);
+        let mut plugins: Vec<PubspecModel> = dependencies.iter().filter(|e| e.is_plugin).cloned().collect();
+        plugins.sort_by_key(|e| e.level);
+        let count = |aurora: PubspecAurora| plugins.iter().filter(|e| e.aurora == Some(aurora.clone())).count();
+        DartPackagesReport {
+            project,
+            date: chrono::offset::Local::now().format("%d-%m-%Y %H:%M:%S").to_string(),
+            count_supported: count(PubspecAurora::Supported),
+            count_port: count(PubspecAurora::Port),
+            count_independent: count(PubspecAurora::Independent),
+            count_outdated: dependencies.iter().filter(|e| e.is_outdated()).count(),
+            count_license_violations: dependencies.iter().filter(|e| e.is_license_violation()).count(),
+            effort: PubspecModel::get_aurora_effort(&plugins),
+            packages,
+            plugins,
+        }
+    }
+}
+
+/// Generate report about dart packages in Flutter project by format
+pub fn gen_report_dart_packages(
+    format: &ReportFormat,
+    project: PubspecModel,
+    dependencies: Vec<PubspecModel>,
+    path_save: &PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let content = match format {
+        ReportFormat::Pdf => return gen_pdf::gen_report_dart_packages(project, dependencies, path_save),
+        ReportFormat::Json => serde_json::to_string_pretty(&DartPackagesReport::new(project, dependencies))?,
+        ReportFormat::Markdown => _gen_markdown(&DartPackagesReport::new(project, dependencies)),
+        ReportFormat::Html => _gen_html(&DartPackagesReport::new(project, dependencies)),
+        ReportFormat::Dot => _gen_dot(&DartPackagesReport::new(project, dependencies)),
+        ReportFormat::Mermaid => _gen_mermaid(&DartPackagesReport::new(project, dependencies)),
+    };
+    fs::write(path_save, content)?;
+    Ok(())
+}
+
+/// Version from pubspec.lock with source: 1.0.0, 1.0.0 (git)
+fn _version_lock(package: &PubspecModel) -> String {
+    match (&package.version_lock, package.source.as_deref()) {
+        (Some(version), Some("hosted")) | (Some(version), None) => version.clone(),
+        (Some(version), Some(source)) => format!("{} ({})", version, source),
+        (None, _) => "-".to_string(),
+    }
+}
+
+/// License package or dash
+fn _license(package: &PubspecModel) -> String {
+    package.license.clone().unwrap_or("-".to_string())
+}
+
+/// Status license by allow-list
+fn _license_status(package: &PubspecModel) -> String {
+    package.license_status.as_ref().map(|e| e.to_name()).unwrap_or_default()
+}
+
+/// Edges graph from project to packages, only to found packages
+fn _graph_edges(report: &DartPackagesReport) -> Vec<(&PubspecModel, &PubspecModel)> {
+    let nodes: Vec<&PubspecModel> = report.packages.iter().chain(report.plugins.iter()).collect();
+    std::iter::once(&report.project)
+        .chain(nodes.iter().copied())
+        .flat_map(|parent| {
+            parent
+                .dependencies
+                .iter()
+                .filter_map(|name| nodes.iter().find(|e| e.name == *name))
+                .map(move |child| (parent, *child))
+        })
+        .collect()
+}
+
+/// Color node graph: plugins by compatibility Aurora OS
+fn _graph_color(package: &PubspecModel) -> Option<&str> {
+    match package.aurora {
+        Some(PubspecAurora::Supported) => Some("#a9dfbf"),
+        Some(PubspecAurora::Port) => Some("#f5b7b1"),
+        Some(PubspecAurora::Independent) => Some("#fad7a0"),
+        None if package.is_plugin => Some("#fad7a0"),
+        None => None,
+    }
+}
+
+fn _gen_dot(report: &DartPackagesReport) -> String {
+    let node = |package: &PubspecModel| {
+        let label = format!(
+            "{}\\n{}\\n{}",
+            package.name,
+            package.version_lock.as_ref().unwrap_or(&package.version),
+            _license(package)
+        );
+        match _graph_color(package) {
+            Some(color) => format!(
+                "  \"{}\" [label=\"{}\", style=filled, fillcolor=\"{}\"];",
+                package.name, label, color
+            ),
+            None => format!("  \"{}\" [label=\"{}\"];", package.name, label),
+        }
+    };
+    let mut lines: Vec<String> = vec![];
+    lines.push("digraph dependencies {".to_string());
+    lines.push("  rankdir=LR;".to_string());
+    lines.push("  node [shape=box, fontname=\"Liberation Sans\"];".to_string());
+    lines.push(format!(
+        "  \"{}\" [label=\"{}\", shape=doubleoctagon];",
+        report.project.name, report.project.name
+    ));
+    for package in report.packages.iter().chain(report.plugins.iter()) {
+        lines.push(node(package));
+    }
+    for (parent, child) in _graph_edges(report) {
+        lines.push(format!("  \"{}\" -> \"{}\";", parent.name, child.name));
+    }
+    lines.push("}".to_string());
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn _gen_mermaid(report: &DartPackagesReport) -> String {
+    // Id node without reserved words: end, graph
+    let id = |package: &PubspecModel| format!("pkg_{}", package.name);
+    let mut lines: Vec<String> = vec![];
+    lines.push("graph LR".to_string());
+    lines.push(format!("  {}{{{{\"{}\"}}}}", id(&report.project), report.project.name));
+    for package in report.packages.iter().chain(report.plugins.iter()) {
+        lines.push(format!(
+            "  {}[\"{}<br>{}<br>{}\"]",
+            id(package),
+            package.name,
+            package.version_lock.as_ref().unwrap_or(&package.version),
+            _license(package)
+        ));
+    }
+    for (parent, child) in _graph_edges(report) {
+        lines.push(format!("  {} --> {}", id(parent), id(child)));
+    }
+    for package in report.packages.iter().chain(report.plugins.iter()) {
+        if let Some(color) = _graph_color(package) {
+            lines.push(format!("  style {} fill:{}", id(package), color));
+        }
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn _gen_markdown(report: &DartPackagesReport) -> String {
+    // Value for cell table
+    let cell = |value: &str| value.replace('|', "\\|").replace('<', "&lt;").replace('\n', " ");
+    let link = |package: &PubspecModel| match &package.pub_dev {
+        Some(url) => format!("[{}]({})", package.name, url),
+        None => package.name.clone(),
+    };
+    let version_lock = |package: &PubspecModel| match package.is_outdated() {
+        true => format!("**{}** (устарел)", _version_lock(package)),
+        false => _version_lock(package),
+    };
+    let mut lines: Vec<String> = vec![];
+    lines.push(format!("# Отчет по зависимостям `{}`", report.project.name));
+    lines.push(String::new());
+    lines.push("Пакеты разделены на платформа не зависимые и платформа зависимые (плагины).".to_string());
+    if !report.plugins.is_empty() {
+        lines.push(String::new());
+        lines.push("## Совместимость с ОС Аврора".to_string());
+        lines.push(String::new());
+        lines.push(format!("- Поддерживается: {}", report.count_supported));
+        lines.push(format!("- Требуется портирование: {}", report.count_port));
+        lines.push(format!("- Не зависит от платформы: {}", report.count_independent));
+        lines.push(format!("- Трудоемкость портирования: **{}**", report.effort));
+    }
+    if report.count_outdated != 0 {
+        lines.push(String::new());
+        lines.push(format!("Устаревшие пакеты: **{}**", report.count_outdated));
+    }
+    if !report.packages.is_empty() {
+        lines.push(String::new());
+        lines.push("## Пакеты".to_string());
+        lines.push(String::new());
+        lines.push("| Пакет | Используется | Последняя | Level | Описание |".to_string());
+        lines.push("|---|---|---|---|---|".to_string());
+        for package in &report.packages {
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} |",
+                link(package),
+                version_lock(package),
+                package.version,
+                package.level,
+                cell(package.description.as_deref().unwrap_or_default())
+            ));
+        }
+    }
+    if !report.plugins.is_empty() {
+        lines.push(String::new());
+        lines.push("## Плагины".to_string());
+        lines.push(String::new());
+        lines.push("| Плагин | Используется | Последняя | Level | ОС Аврора | Описание |".to_string());
+        lines.push("|---|---|---|---|---|---|".to_string());
+        for plugin in &report.plugins {
+            lines.push(format!(
+                "| {} | {} | {} | {} | {} | {} |",
+                link(plugin),
+                version_lock(plugin),
+                plugin.version,
+                plugin.level,
+                plugin.aurora.as_ref().map(|e| e.to_name()).unwrap_or_default(),
+                cell(plugin.description.as_deref().unwrap_or_default())
+            ));
+        }
+    }
+    if !report.packages.is_empty() || !report.plugins.is_empty() {
+        lines.push(String::new());
+        lines.push("## Лицензии".to_string());
+        lines.push(String::new());
+        if report.count_license_violations != 0 {
+            lines.push(format!("Нарушения лицензий: **{}**", report.count_license_violations));
+            lines.push(String::new());
+        }
+        lines.push("| Пакет | Лицензия | Статус |".to_string());
+        lines.push("|---|---|---|".to_string());
+        for package in report.packages.iter().chain(report.plugins.iter()) {
+            let status = _license_status(package);
+            lines.push(format!(
+                "| {} | {} | {} |",
+                package.name,
+                _license(package),
+                match package.is_license_violation() {
+                    true => format!("**{}**", status),
+                    false => status,
+                }
+            ));
+        }
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "_Отчёт генерируется на основе версий из pubspec.lock или актуальных пакетов, {}._",
+        report.date
+    ));
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn _gen_html(report: &DartPackagesReport) -> String {
+    let escape = |value: &str| {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    };
+    let link = |package: &PubspecModel| match &package.pub_dev {
+        Some(url) => format!("<a href=\"{}\">{}</a>", escape(url), escape(&package.name)),
+        None => escape(&package.name),
+    };
+    let version_lock = |package: &PubspecModel| match package.is_outdated() {
+        true => format!(
+            "<span class=\"port\">{} (устарел)</span>",
+            escape(&_version_lock(package))
+        ),
+        false => escape(&_version_lock(package)),
+    };
+    let mut body: Vec<String> = vec![];
+    body.push(format!(
+        "<h1>Отчет по зависимостям {}</h1>",
+        escape(&report.project.name)
+    ));
+    body.push("<p>Пакеты разделены на платформа не зависимые и платформа зависимые (плагины).</p>".to_string());
+    if !report.plugins.is_empty() {
+        body.push("<h2>Совместимость с ОС Аврора</h2>".to_string());
+        body.push("<ul>".to_string());
+        body.push(format!("<li>Поддерживается: {}</li>", report.count_supported));
+        body.push(format!("<li>Требуется портирование: {}</li>", report.count_port));
+        body.push(format!(
+            "<li>Не зависит от платформы: {}</li>",
+            report.count_independent
+        ));
+        body.push(format!(
+            "<li>Трудоемкость портирования: <b>{}</b></li>",
+            escape(&report.effort)
+        ));
+        body.push("</ul>".to_string());
+    }
+    if report.count_outdated != 0 {
+        body.push(format!("<p>Устаревшие пакеты: <b>{}</b></p>", report.count_outdated));
+    }
+    if !report.packages.is_empty() {
+        body.push("<h2>Пакеты</h2>".to_string());
+        body.push("<table>".to_string());
+        body.push(
+            "<tr><th>Пакет</th><th>Используется</th><th>Последняя</th><th>Level</th><th>Описание</th></tr>".to_string(),
+        );
+        for package in &report.packages {
+            body.push(format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                link(package),
+                version_lock(package),
+                escape(&package.version),
+                package.level,
+                escape(package.description.as_deref().unwrap_or_default())
+            ));
+        }
+        body.push("</table>".to_string());
+    }
+    if !report.plugins.is_empty() {
+        body.push("<h2>Плагины</h2>".to_string());
+        body.push("<table>".to_string());
+        body.push(
+            "<tr><th>Плагин</th><th>Используется</th><th>Последняя</th><th>Level</th><th>ОС Аврора</th><th>Описание</th></tr>".to_string(),
+        );
+        for plugin in &report.plugins {
+            let (class, name) = match &plugin.aurora {
+                Some(aurora @ PubspecAurora::Supported) => ("supported", aurora.to_name()),
+                Some(aurora @ PubspecAurora::Port) => ("port", aurora.to_name()),
+                Some(aurora @ PubspecAurora::Independent) => ("independent", aurora.to_name()),
+                None => ("independent", String::new()),
+            };
+            body.push(format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td class=\"{}\">{}</td><td>{}</td></tr>",
+                link(plugin),
+                version_lock(plugin),
+                escape(&plugin.version),
+                plugin.level,
+                class,
+                escape(&name),
+                escape(plugin.description.as_deref().unwrap_or_default())
+            ));
+        }
+        body.push("</table>".to_string());
+    }
+    if !report.packages.is_empty() || !report.plugins.is_empty() {
+        body.push("<h2>Лицензии</h2>".to_string());
+        if report.count_license_violations != 0 {
+            body.push(format!(
+                "<p>Нарушения лицензий: <b>{}</b></p>",
+                report.count_license_violations
+            ));
+        }
+        body.push("<table>".to_string());
+        body.push("<tr><th>Пакет</th><th>Лицензия</th><th>Статус</th></tr>".to_string());
+        for package in report.packages.iter().chain(report.plugins.iter()) {
+            body.push(format!(
+                "<tr><td>{}</td><td>{}</td><td class=\"{}\">{}</td></tr>",
+                escape(&package.name),
+                escape(&_license(package)),
+                if package.is_license_violation() {
+                    "port"
+                } else {
+                    "supported"
+                },
+                escape(&_license_status(package))
+            ));
+        }
+        body.push("</table>".to_string());
+    }
+    body.push(format!(
+        "<p class=\"footer\">*Отчёт генерируется на основе версий из pubspec.lock или актуальных пакетов.<br>{}</p>",
+        report.date
+    ));
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="ru">
+<head>
+<meta charset="utf-8">
+<title>Отчет pubspec.yaml</title>
+<style>
+body {{ font-family: "Liberation Sans", "DejaVu Sans", sans-serif; margin: 2em auto; max-width: 960px; color: #202124; }}
+h1, h2 {{ color: #00579b; }}
+table {{ border-collapse: collapse; width: 100%; }}
+th, td {{ border: 1px solid #dadce0; padding: 6px 8px; text-align: left; vertical-align: top; }}
+a {{ color: #1967d2; }}
+.supported {{ color: #1e824c; }}
+.port {{ color: #c0392b; }}
+.independent {{ color: #6d6d6d; }}
+.footer {{ color: #6d6d6d; font-style: italic; font-size: 0.9em; }}
+</style>
+</head>
+<body>
+{}
+</body>
+</html>
+"#,
+        body.join("\n")
+    )
+}
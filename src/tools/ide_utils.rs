@@ -0,0 +1,195 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use crate::models::psdk_target::model::PsdkTargetModel;
+
+use super::macros::tr;
+
+/// Dir in project for generated files
+pub const IDE_DIR: &str = ".aurora-ide";
+
+pub struct IdeResult {
+    pub toolchain: PathBuf,
+    pub compile_commands: PathBuf,
+    pub clangd: Option<PathBuf>,
+    pub vscode: PathBuf,
+}
+
+/// Build dir for configure project in Platform SDK
+pub fn get_build_dir(path: &Path, target: &PsdkTargetModel) -> PathBuf {
+    path.join(IDE_DIR).join(&target.full_name)
+}
+
+/// Dir tooling on host: {root}/toolings/{tooling}
+pub fn get_tooling_dir(target: &PsdkTargetModel) -> String {
+    let root = target.dir.replace(&format!("/targets/{}", target.full_name), "");
+    format!("{root}/toolings/{}", target.tooling)
+}
+
+/// GNU triple cross compiler tooling
+pub fn get_triple(arch: &str) -> String {
+    match arch {
+        "armv7hl" => "armv7hl-meego-linux-gnueabi".to_string(),
+        _ => format!("{arch}-meego-linux-gnu"),
+    }
+}
+
+/// Generate files IDE by configured build dir
+pub fn gen_ide_files(path: &Path, target: &PsdkTargetModel) -> Result<IdeResult, Box<dyn std::error::Error>> {
+    let build_dir = get_build_dir(path, target);
+    let compile_commands = build_dir.join("compile_commands.json");
+    if !compile_commands.exists() {
+        Err(tr!("не найден compile_commands.json, проверьте CMake проекта"))?
+    }
+    let toolchain = gen_toolchain(&build_dir, target)?;
+    let compile_commands = gen_compile_commands(&compile_commands, &path.join("compile_commands.json"), target)?;
+    let clangd = gen_clangd(path, target)?;
+    let vscode = gen_vscode(path, target)?;
+    Ok(IdeResult {
+        toolchain,
+        compile_commands,
+        clangd,
+        vscode,
+    })
+}
+
+/// CMake toolchain with sysroot target
+fn gen_toolchain(build_dir: &Path, target: &PsdkTargetModel) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let cross = format!("{}/opt/cross/bin/{}", get_tooling_dir(target), get_triple(&target.arch));
+    let lib = if target.arch == "aarch64" || target.arch == "x86_64" {
+        "lib64"
+    } else {
+        "lib"
+    };
+    let content = format!(
+        r#"# Generated by aurora-bot for {name}
+set(CMAKE_SYSTEM_NAME Linux)
+set(CMAKE_SYSTEM_PROCESSOR {arch})
+
+set(CMAKE_SYSROOT {sysroot})
+set(CMAKE_FIND_ROOT_PATH {sysroot})
+
+set(CMAKE_C_COMPILER {cross}-gcc)
+set(CMAKE_CXX_COMPILER {cross}-g++)
+
+set(CMAKE_FIND_ROOT_PATH_MODE_PROGRAM NEVER)
+set(CMAKE_FIND_ROOT_PATH_MODE_LIBRARY ONLY)
+set(CMAKE_FIND_ROOT_PATH_MODE_INCLUDE ONLY)
+set(CMAKE_FIND_ROOT_PATH_MODE_PACKAGE ONLY)
+
+set(ENV{{PKG_CONFIG_SYSROOT_DIR}} {sysroot})
+set(ENV{{PKG_CONFIG_LIBDIR}} {sysroot}/usr/{lib}/pkgconfig:{sysroot}/usr/share/pkgconfig)
+"#,
+        name = target.full_name,
+        arch = target.arch,
+        sysroot = target.dir,
+        cross = cross,
+        lib = lib,
+    );
+    let path = build_dir.join("toolchain.cmake");
+    fs::write(&path, content)?;
+    Ok(path)
+}
+
+/// Remap paths chroot to host
+fn gen_compile_commands(
+    path_in: &Path,
+    path_out: &Path,
+    target: &PsdkTargetModel,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut commands: Value = serde_json::from_str(&fs::read_to_string(path_in)?)?;
+    let items = match commands.as_array_mut() {
+        Some(value) => value,
+        None => Err(tr!("не удалось прочитать compile_commands.json"))?,
+    };
+    for item in items {
+        if let Some(Value::String(command)) = item.get_mut("command") {
+            *command = remap_command(command, target);
+        }
+        if let Some(Value::Array(arguments)) = item.get_mut("arguments") {
+            for argument in arguments {
+                if let Value::String(value) = argument {
+                    *value = remap_command(value, target);
+                }
+            }
+        }
+    }
+    fs::write(path_out, serde_json::to_string_pretty(&commands)?)?;
+    Ok(path_out.to_path_buf())
+}
+
+/// The chroot maps /srv/mer to the Platform SDK root, /usr of target is sysroot
+fn remap_command(command: &str, target: &PsdkTargetModel) -> String {
+    let root = target.dir.replace(&format!("/targets/{}", target.full_name), "");
+    let cross = format!("{}/opt/cross/bin/{}", get_tooling_dir(target), get_triple(&target.arch));
+    command
+        .replace("/srv/mer/targets/", &format!("{root}/targets/"))
+        .replace("/srv/mer/toolings/", &format!("{root}/toolings/"))
+        .replace("-I/usr/", &format!("-I{}/usr/", target.dir))
+        .replace("-isystem /usr/", &format!("-isystem {}/usr/", target.dir))
+        .replace("/usr/bin/cc ", &format!("{cross}-gcc "))
+        .replace("/usr/bin/c++ ", &format!("{cross}-g++ "))
+}
+
+/// Config clangd, keep user config
+fn gen_clangd(path: &Path, target: &PsdkTargetModel) -> Result<Option<PathBuf>, Box<dyn std::error::Error>> {
+    let path = path.join(".clangd");
+    if path.exists() {
+        return Ok(None);
+    }
+    let content = format!(
+        r#"CompileFlags:
+  CompilationDatabase: .
+  Add: [--sysroot={sysroot}, --target={triple}]
+  Remove: [-mfloat-abi=*, -mfpu=*, -fmessage-length=*]
+"#,
+        sysroot = target.dir,
+        triple = get_triple(&target.arch),
+    );
+    fs::write(&path, content)?;
+    Ok(Some(path))
+}
+
+/// Settings VS Code, update only own keys
+fn gen_vscode(path: &Path, target: &PsdkTargetModel) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dir = path.join(".vscode");
+    fs::create_dir_all(&dir)?;
+    let path_settings = dir.join("settings.json");
+    let mut settings: Value = match fs::read_to_string(&path_settings) {
+        Ok(value) => match serde_json::from_str(&value) {
+            Ok(value) => value,
+            Err(_) => Err(tr!("не удалось прочитать .vscode/settings.json"))?,
+        },
+        Err(_) => Value::Object(Default::default()),
+    };
+    let object = match settings.as_object_mut() {
+        Some(value) => value,
+        None => Err(tr!("не удалось прочитать .vscode/settings.json"))?,
+    };
+    let cross = format!("{}/opt/cross/bin/*", get_tooling_dir(target));
+    object.insert(
+        "clangd.arguments".into(),
+        serde_json::json!([
+            format!("--compile-commands-dir={}", path.to_string_lossy()),
+            format!("--query-driver={cross}")
+        ]),
+    );
+    // Keep user configure settings, update only toolchain
+    let configure = object
+        .entry("cmake.configureSettings")
+        .or_insert_with(|| Value::Object(Default::default()));
+    if !configure.is_object() {
+        *configure = Value::Object(Default::default());
+    }
+    if let Some(configure) = configure.as_object_mut() {
+        configure.insert(
+            "CMAKE_TOOLCHAIN_FILE".into(),
+            serde_json::json!(get_build_dir(path, target).join("toolchain.cmake").to_string_lossy()),
+        );
+    }
+    fs::write(&path_settings, serde_json::to_string_pretty(&settings)?)?;
+    Ok(path_settings)
+}
@@ -0,0 +1,164 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+use yaml_rust::YamlLoader;
+
+use super::macros::tr;
+
+/// Config licenses in directory project near pubspec.yaml
+pub const LICENSE_CONFIG: &str = ".aurora-license.yaml";
+
+/// Allowed licenses by default, id as tags pub.dev
+const LICENSE_ALLOW: [&str; 10] = [
+    "mit",
+    "bsd-2-clause",
+    "bsd-3-clause",
+    "apache-2.0",
+    "isc",
+    "zlib",
+    "bsl-1.0",
+    "unlicense",
+    "0bsd",
+    "cc0-1.0",
+];
+
+/// Prefixes copyleft licenses
+const LICENSE_COPYLEFT: [&str; 8] = ["gpl", "lgpl", "agpl", "mpl", "epl", "cddl", "eupl", "osl"];
+
+/// Files with license text in package
+const LICENSE_FILES: [&str; 5] = ["LICENSE", "LICENSE.md", "LICENSE.txt", "COPYING", "LICENCE"];
+
+/// Allowed - in allow-list, Copyleft and Denied - not in allow-list, Unknown - license not found,
+/// Failed - license not received from pub.dev
+#[derive(Serialize, Clone, PartialEq, Debug)]
+pub enum LicenseStatus {
+    Allowed,
+    Copyleft,
+    Denied,
+    Unknown,
+    Failed,
+}
+
+impl LicenseStatus {
+    pub fn to_name(&self) -> String {
+        match self {
+            LicenseStatus::Allowed => tr!("разрешена"),
+            LicenseStatus::Copyleft => tr!("copyleft"),
+            LicenseStatus::Denied => tr!("не разрешена"),
+            LicenseStatus::Unknown => tr!("неизвестна"),
+            LicenseStatus::Failed => tr!("не удалось получить"),
+        }
+    }
+}
+
+pub struct LicenseConfig {
+    pub allow: Vec<String>,
+    pub ignore: Vec<String>,
+}
+
+impl LicenseConfig {
+    /// Load config project, without file allowed permissive licenses
+    pub fn load(path: &Path) -> Result<LicenseConfig, Box<dyn std::error::Error>> {
+        let mut config = LicenseConfig {
+            allow: LICENSE_ALLOW.iter().map(|e| e.to_string()).collect(),
+            ignore: vec![],
+        };
+        let file = path.join(LICENSE_CONFIG);
+        if !file.exists() {
+            return Ok(config);
+        }
+        let docs = match YamlLoader::load_from_str(&fs::read_to_string(&file)?) {
+            Ok(value) => value,
+            Err(_) => Err(tr!("не удалось прочитать {}", LICENSE_CONFIG))?,
+        };
+        let Some(doc) = docs.first() else {
+            return Ok(config);
+        };
+        let to_list = |values: &Vec<yaml_rust::Yaml>| {
+            values
+                .iter()
+                .filter_map(|e| e.as_str())
+                .map(|e| e.to_lowercase())
+                .collect::<Vec<String>>()
+        };
+        if let Some(allow) = doc["allow"].as_vec() {
+            config.allow = to_list(allow);
+        }
+        if let Some(ignore) = doc["ignore"].as_vec() {
+            config.ignore = to_list(ignore);
+        }
+        Ok(config)
+    }
+
+    /// Check license package by allow-list
+    pub fn check(&self, name: &str, license: Option<&String>) -> LicenseStatus {
+        if self.ignore.iter().any(|e| e == name) {
+            return LicenseStatus::Allowed;
+        }
+        let Some(license) = license else {
+            return LicenseStatus::Unknown;
+        };
+        if self.allow.contains(license) {
+            LicenseStatus::Allowed
+        } else if LICENSE_COPYLEFT.iter().any(|e| license.starts_with(e)) {
+            LicenseStatus::Copyleft
+        } else {
+            LicenseStatus::Denied
+        }
+    }
+}
+
+/// License from tags pub.dev: license:bsd-3-clause
+pub fn license_from_tags(tags: &[String]) -> Option<String> {
+    tags.iter()
+        .filter_map(|e| e.strip_prefix("license:"))
+        .find(|e| !["fsf-libre", "osi-approved", "unknown"].contains(e))
+        .map(|e| e.to_string())
+}
+
+/// License by text file in directory package
+pub fn license_from_dir(path: &Path) -> Option<String> {
+    let text = LICENSE_FILES
+        .iter()
+        .find_map(|e| fs::read_to_string(path.join(e)).ok())?;
+    let text = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let license = if text.contains("GNU AFFERO GENERAL PUBLIC LICENSE") {
+        "agpl-3.0"
+    } else if text.contains("GNU LESSER GENERAL PUBLIC LICENSE") {
+        if text.contains("Version 3") {
+            "lgpl-3.0"
+        } else {
+            "lgpl-2.1"
+        }
+    } else if text.contains("GNU GENERAL PUBLIC LICENSE") {
+        if text.contains("Version 3") {
+            "gpl-3.0"
+        } else {
+            "gpl-2.0"
+        }
+    } else if text.contains("Mozilla Public License") {
+        "mpl-2.0"
+    } else if text.contains("Apache License") {
+        "apache-2.0"
+    } else if text.contains("Permission is hereby granted, free of charge") {
+        "mit"
+    } else if text.contains("Redistribution and use in source and binary forms") {
+        if text.contains("Neither the name") {
+            "bsd-3-clause"
+        } else {
+            "bsd-2-clause"
+        }
+    } else if text.contains("Permission to use, copy, modify, and/or distribute") {
+        "isc"
+    } else if text.contains("This is free and unencumbered software") {
+        "unlicense"
+    } else if text.contains("Boost Software License") {
+        "bsl-1.0"
+    } else if text.contains("provided 'as-is', without any express or implied warranty") {
+        "zlib"
+    } else {
+        return None;
+    };
+    Some(license.to_string())
+}
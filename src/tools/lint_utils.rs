@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use regex::Regex;
+use serde::Deserialize;
+use serde::Serialize;
+use walkdir::WalkDir;
+
+use super::macros::tr;
+use super::project_utils::ICON_SIZES;
+
+/// Dirs with spec in project: C++/QML and Flutter
+const SPEC_DIRS: [&str; 2] = ["rpm", "aurora/rpm"];
+
+/// Tags required by Aurora packaging rules
+const SPEC_REQUIRED_TAGS: [&str; 5] = ["Name", "Version", "Release", "Summary", "License"];
+
+/// Packages application can't require
+const SPEC_FORBIDDEN_REQUIRES: [&str; 8] = ["sudo", "ssu", "zypper", "pkcon", "rpm", "python3", "perl", "bash"];
+
+/// Scriptlets forbidden for applications
+const SPEC_FORBIDDEN_SECTIONS: [&str; 9] = [
+    "%pre",
+    "%post",
+    "%preun",
+    "%postun",
+    "%pretrans",
+    "%posttrans",
+    "%triggerin",
+    "%triggerun",
+    "%triggerpostun",
+];
+
+/// Sections spec, other lines with % are macros
+const SPEC_SECTIONS: [&str; 9] = [
+    "%description",
+    "%package",
+    "%prep",
+    "%build",
+    "%install",
+    "%check",
+    "%clean",
+    "%files",
+    "%changelog",
+];
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub enum LintLevel {
+    Error,
+    Warning,
+}
+
+/// Finding with location, path relative project
+#[derive(Serialize, Deserialize, Clone)]
+pub struct LintFinding {
+    pub file: String,
+    pub line: Option<usize>,
+    pub level: LintLevel,
+    pub rule: String,
+    pub message: String,
+}
+
+impl LintFinding {
+    fn new(file: &str, line: Option<usize>, level: LintLevel, rule: &str, message: String) -> LintFinding {
+        LintFinding {
+            file: file.to_string(),
+            line,
+            level,
+            rule: rule.to_string(),
+            message,
+        }
+    }
+
+    /// Location file:line
+    pub fn location(&self) -> String {
+        match self.line {
+            Some(line) => format!("{}:{}", self.file, line),
+            None => self.file.clone(),
+        }
+    }
+}
+
+/// Parsed spec, lines numbered from 1
+struct SpecFile {
+    file: String,
+    tags: Vec<(String, String, usize)>,
+    macros: HashMap<String, String>,
+    scriptlets: Vec<(String, usize)>,
+    files: Vec<(String, usize)>,
+}
+
+impl SpecFile {
+    fn parse(path: &Path, file: String) -> Result<SpecFile, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        let re_tag = Regex::new(r"^([A-Za-z][A-Za-z0-9]*(\([a-z,]+\))?)\s*:\s*(.*)$")?;
+        let re_macro = Regex::new(r"^%(define|global)\s+(\S+)\s+(.*)$")?;
+        let mut spec = SpecFile {
+            file,
+            tags: vec![],
+            macros: HashMap::from([
+                ("_bindir".to_string(), "/usr/bin".to_string()),
+                ("_datadir".to_string(), "/usr/share".to_string()),
+            ]),
+            scriptlets: vec![],
+            files: vec![],
+        };
+        let mut section: Option<String> = None;
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+            let number = index + 1;
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(caps) = re_macro.captures(line) {
+                spec.macros.insert(caps[2].to_string(), caps[3].trim().to_string());
+                continue;
+            }
+            let word = line.split_whitespace().next().unwrap_or_default();
+            if SPEC_SECTIONS.contains(&word) || SPEC_FORBIDDEN_SECTIONS.contains(&word) {
+                if SPEC_FORBIDDEN_SECTIONS.contains(&word) {
+                    spec.scriptlets.push((word.to_string(), number));
+                }
+                section = Some(word.to_string());
+                continue;
+            }
+            match section.as_deref() {
+                None | Some("%package") => {
+                    if let Some(caps) = re_tag.captures(line) {
+                        let tag = caps[1].to_string();
+                        let value = caps[3].trim().to_string();
+                        if ["Name", "Version", "Release"].contains(&tag.as_str()) {
+                            spec.macros.insert(tag.to_lowercase(), value.clone());
+                        }
+                        spec.tags.push((tag, value, number));
+                    }
+                }
+                Some("%files") => spec.files.push((line.to_string(), number)),
+                _ => {}
+            }
+        }
+        Ok(spec)
+    }
+
+    fn get_tag(&self, name: &str) -> Option<(String, usize)> {
+        self.tags
+            .iter()
+            .find(|(tag, _, _)| tag == name)
+            .map(|(_, value, line)| (self.expand(value), *line))
+    }
+
+    /// Expand simple macros: %{name}, %name
+    fn expand(&self, value: &str) -> String {
+        let mut result = value.to_string();
+        // Nested macros
+        for _ in 0..3 {
+            for (key, macro_value) in &self.macros {
+                result = result
+                    .replace(&format!("%{{{}}}", key), macro_value)
+                    .replace(&format!("%{}", key), macro_value);
+            }
+        }
+        result
+    }
+
+    fn has_file(&self, predicate: impl Fn(&str) -> bool) -> bool {
+        self.files.iter().any(|(line, _)| predicate(&self.expand(line)))
+    }
+}
+
+/// Lint specs project by Aurora packaging rules
+pub fn lint_project(path: &Path) -> Result<Vec<LintFinding>, Box<dyn std::error::Error>> {
+    if !path.is_dir() {
+        Err(tr!("укажите директорию проекта"))?
+    }
+    let specs = search_specs(path);
+    if specs.is_empty() {
+        return Ok(vec![LintFinding::new(
+            SPEC_DIRS[0],
+            None,
+            LintLevel::Error,
+            "spec-missing",
+            tr!("не найден файл .spec в директориях: {}", SPEC_DIRS.join(", ")),
+        )]);
+    }
+    let mut findings: Vec<LintFinding> = vec![];
+    for spec_path in specs {
+        let file = relative(path, &spec_path);
+        let spec = SpecFile::parse(&spec_path, file)?;
+        findings.append(&mut lint_spec(path, &spec)?);
+    }
+    Ok(findings)
+}
+
+fn lint_spec(path: &Path, spec: &SpecFile) -> Result<Vec<LintFinding>, Box<dyn std::error::Error>> {
+    let mut findings: Vec<LintFinding> = vec![];
+    let error = |line: Option<usize>, rule: &str, message: String| {
+        LintFinding::new(&spec.file, line, LintLevel::Error, rule, message)
+    };
+    // Tags
+    for tag in SPEC_REQUIRED_TAGS {
+        if spec.get_tag(tag).is_none() {
+            findings.push(error(None, "tag-required", tr!("отсутствует обязательный тег {}", tag)));
+        }
+    }
+    for (tag, value, line) in spec.tags.iter().filter(|(tag, _, _)| tag.starts_with("Requires")) {
+        for package in value.split([',', ' ']).filter(|e| !e.is_empty()) {
+            if SPEC_FORBIDDEN_REQUIRES.contains(&package) {
+                findings.push(error(
+                    Some(*line),
+                    "requires-forbidden",
+                    tr!("запрещенная зависимость {}: {}", tag, package),
+                ));
+            }
+        }
+    }
+    for (section, line) in &spec.scriptlets {
+        findings.push(error(
+            Some(*line),
+            "scriptlet-forbidden",
+            tr!("секция {} запрещена для приложений", section),
+        ));
+    }
+    let Some((name, name_line)) = spec.get_tag("Name") else {
+        return Ok(findings);
+    };
+    if !Regex::new(r"^[a-z]{2,}\.[a-zA-Z][a-zA-Z0-9_]*\.[a-zA-Z][a-zA-Z0-9_]*$")?.is_match(&name) {
+        findings.push(error(
+            Some(name_line),
+            "name-format",
+            tr!("Name должен совпадать с id приложения <domain>.<org>.<app>: {}", name),
+        ));
+    }
+    // Files
+    if !spec.has_file(|e| e.contains(&format!("/usr/share/applications/{}.desktop", name))) {
+        findings.push(error(
+            spec.files.first().map(|(_, line)| *line),
+            "files-desktop",
+            tr!("в %files отсутствует /usr/share/applications/{}.desktop", name),
+        ));
+    }
+    if !spec.has_file(|e| e.contains("/usr/share/icons/hicolor/*/")) {
+        for size in ICON_SIZES {
+            if !spec.has_file(|e| e.contains(&format!("/usr/share/icons/hicolor/{size}x{size}/"))) {
+                findings.push(error(
+                    spec.files.first().map(|(_, line)| *line),
+                    "files-icons",
+                    tr!("в %files отсутствует иконка {}x{}", size, size),
+                ));
+            }
+        }
+    }
+    if !spec.has_file(|e| e.contains(&format!("/usr/bin/{}", name))) {
+        findings.push(LintFinding::new(
+            &spec.file,
+            spec.files.first().map(|(_, line)| *line),
+            LintLevel::Warning,
+            "files-binary",
+            tr!("в %files отсутствует /usr/bin/{}", name),
+        ));
+    }
+    // Project files
+    let project_files = search_project_files(path);
+    match project_files.iter().find(|e| {
+        e.file_name()
+            .is_some_and(|n| n.to_string_lossy() == format!("{}.desktop", name))
+    }) {
+        Some(desktop) => findings.append(&mut lint_desktop(path, desktop, &name)?),
+        None => findings.push(error(
+            Some(name_line),
+            "desktop-missing",
+            tr!("не найден файл {}.desktop", name),
+        )),
+    }
+    for size in ICON_SIZES {
+        let dir = format!("{size}x{size}");
+        let is_exist = project_files.iter().any(|e| {
+            e.extension().is_some_and(|e| e == "png")
+                && relative(path, e)
+                    .split('/')
+                    .any(|e| e == dir || e == format!("{dir}.png"))
+        });
+        if !is_exist {
+            findings.push(error(
+                Some(name_line),
+                "icon-missing",
+                tr!("не найдена иконка {} в проекте", dir),
+            ));
+        }
+    }
+    Ok(findings)
+}
+
+/// Desktop file must match package name
+fn lint_desktop(path: &Path, desktop: &Path, name: &str) -> Result<Vec<LintFinding>, Box<dyn std::error::Error>> {
+    let file = relative(path, desktop);
+    let content = fs::read_to_string(desktop)?;
+    let mut findings: Vec<LintFinding> = vec![];
+    let mut values: HashMap<String, (String, usize)> = HashMap::new();
+    for (index, line) in content.lines().enumerate() {
+        if let Some((key, value)) = line.split_once('=') {
+            values.insert(key.trim().to_string(), (value.trim().to_string(), index + 1));
+        }
+    }
+    match values.get("Icon") {
+        Some((icon, line)) if icon != name => findings.push(LintFinding::new(
+            &file,
+            Some(*line),
+            LintLevel::Error,
+            "desktop-icon",
+            tr!("Icon должен совпадать с Name из spec: {} != {}", icon, name),
+        )),
+        None => findings.push(LintFinding::new(
+            &file,
+            None,
+            LintLevel::Error,
+            "desktop-icon",
+            tr!("отсутствует ключ Icon"),
+        )),
+        _ => {}
+    }
+    if let (Some((organization, line)), Some((application, _))) =
+        (values.get("OrganizationName"), values.get("ApplicationName"))
+    {
+        let id = format!("{}.{}", organization, application);
+        if id != name {
+            findings.push(LintFinding::new(
+                &file,
+                Some(*line),
+                LintLevel::Error,
+                "desktop-app-id",
+                tr!(
+                    "OrganizationName.ApplicationName не совпадает с Name из spec: {} != {}",
+                    id,
+                    name
+                ),
+            ));
+        }
+    }
+    Ok(findings)
+}
+
+fn search_specs(path: &Path) -> Vec<PathBuf> {
+    let mut result: Vec<PathBuf> = vec![];
+    for dir in SPEC_DIRS {
+        let Ok(entries) = fs::read_dir(path.join(dir)) else {
+            continue;
+        };
+        let mut specs = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|e| e.extension().is_some_and(|e| e == "spec"))
+            .collect::<Vec<PathBuf>>();
+        specs.sort();
+        result.append(&mut specs);
+    }
+    result
+}
+
+/// Files project without hidden dirs and build
+pub fn search_project_files(path: &Path) -> Vec<PathBuf> {
+    WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0 || {
+                let name = e.file_name().to_string_lossy();
+                !name.starts_with('.') && name != "build"
+            }
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect()
+}
+
+pub fn relative(path: &Path, file_path: &Path) -> String {
+    file_path
+        .strip_prefix(path)
+        .unwrap_or(file_path)
+        .to_string_lossy()
+        .to_string()
+}
@@ -3,9 +3,17 @@ pub mod enums;
 pub mod ffmpeg_utils;
 pub mod format_utils;
 pub mod gen_pdf;
+pub mod gen_report;
+pub mod ide_utils;
+pub mod license_utils;
+pub mod lint_utils;
 pub mod macros;
 pub mod programs;
+pub mod project_templates;
+pub mod project_utils;
+pub mod rpm_utils;
 pub mod single;
 pub mod telegram;
 pub mod terminal;
 pub mod utils;
+pub mod validate_utils;
@@ -40,6 +40,30 @@ pub fn get_clang_format() -> Result<String, Box<dyn std::error::Error>> {
     Err(tr!("не найден clang-format"))?
 }
 
+pub fn get_qmlformat() -> Result<String, Box<dyn std::error::Error>> {
+    if let Ok(_) = exec::exec_wait_args("qmlformat", ["--version"]) {
+        return Ok("qmlformat".into());
+    }
+    Err(tr!("не найден qmlformat"))?
+}
+
+pub fn get_cmake_format() -> Result<String, Box<dyn std::error::Error>> {
+    if let Ok(_) = exec::exec_wait_args("cmake-format", ["--version"]) {
+        return Ok("cmake-format".into());
+    }
+    if let Ok(_) = exec::exec_wait_args("gersemi", ["--version"]) {
+        return Ok("gersemi".into());
+    }
+    Err(tr!("не найден cmake-format"))?
+}
+
+pub fn get_diff() -> Result<String, Box<dyn std::error::Error>> {
+    if let Ok(_) = exec::exec_wait_args("diff", ["--version"]) {
+        return Ok("diff".into());
+    }
+    Err(tr!("не найден diff"))?
+}
+
 pub fn get_sudo() -> Result<String, Box<dyn std::error::Error>> {
     if let Ok(_) = exec::exec_wait_args("sudo", ["--version"]) {
         return Ok("sudo".into());
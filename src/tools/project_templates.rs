@@ -0,0 +1,417 @@
+// Placeholders: {{package}} - ru.org.app, {{organization}} - ru.org, {{app}} - app
+
+pub const CLANG_FORMAT: &str = r#"---
+BasedOnStyle: Chromium
+ColumnLimit: 120
+ReflowComments: false
+IndentWidth: 4
+AccessModifierOffset: -4
+...
+"#;
+
+pub const GITIGNORE_CPP: &str = r#"build/
+*.user
+.aurora-ide/
+compile_commands.json
+"#;
+
+pub const GITIGNORE_FLUTTER: &str = r#".dart_tool/
+.packages
+.pub-cache/
+.pub/
+build/
+pubspec.lock
+.flutter-plugins
+.flutter-plugins-dependencies
+aurora/flutter/
+"#;
+
+/////////////////
+// QML application
+
+pub const QML_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.5)
+
+project({{package}} CXX)
+
+find_package(Qt5 COMPONENTS Core Qml Gui Quick REQUIRED)
+
+include(FindPkgConfig)
+pkg_search_module(AURORA auroraapp REQUIRED)
+
+set(CMAKE_AUTOMOC ON)
+set(CMAKE_INCLUDE_CURRENT_DIR ON)
+set(CMAKE_CXX_STANDARD 17)
+set(CMAKE_CXX_STANDARD_REQUIRED ON)
+
+add_executable(${PROJECT_NAME} src/main.cpp)
+
+target_compile_definitions(${PROJECT_NAME} PRIVATE
+    $<$<OR:$<CONFIG:Debug>,$<CONFIG:RelWithDebInfo>>:QT_QML_DEBUG>
+)
+target_include_directories(${PROJECT_NAME} PRIVATE
+    $<BUILD_INTERFACE:${AURORA_INCLUDE_DIRS}>
+)
+target_link_libraries(${PROJECT_NAME}
+    Qt5::Quick
+    ${AURORA_LDFLAGS}
+)
+
+install(TARGETS ${PROJECT_NAME}
+    RUNTIME DESTINATION bin
+)
+install(DIRECTORY qml
+    DESTINATION share/${PROJECT_NAME}
+)
+install(FILES ${PROJECT_NAME}.desktop
+    DESTINATION share/applications
+)
+
+foreach(_size 86 108 128 172)
+    install(FILES icons/${_size}x${_size}/${PROJECT_NAME}.png
+        DESTINATION share/icons/hicolor/${_size}x${_size}/apps
+    )
+endforeach()
+"#;
+
+pub const QML_MAIN_CPP: &str = r#"#include <auroraapp.h>
+#include <QtQuick>
+
+int main(int argc, char* argv[]) {
+    QScopedPointer<QGuiApplication> application(Aurora::Application::application(argc, argv));
+    application->setOrganizationName(QStringLiteral("{{organization}}"));
+    application->setApplicationName(QStringLiteral("{{app}}"));
+
+    QScopedPointer<QQuickView> view(Aurora::Application::createView());
+    view->setSource(Aurora::Application::pathTo(QStringLiteral("qml/{{app}}.qml")));
+    view->show();
+
+    return application->exec();
+}
+"#;
+
+pub const QML_APP: &str = r#"import QtQuick 2.0
+import Sailfish.Silica 1.0
+
+ApplicationWindow {
+    objectName: "applicationWindow"
+    initialPage: Qt.resolvedUrl("pages/MainPage.qml")
+    cover: Qt.resolvedUrl("cover/DefaultCoverPage.qml")
+    allowedOrientations: defaultAllowedOrientations
+}
+"#;
+
+pub const QML_MAIN_PAGE: &str = r#"import QtQuick 2.0
+import Sailfish.Silica 1.0
+
+Page {
+    objectName: "mainPage"
+    allowedOrientations: Orientation.All
+
+    PageHeader {
+        objectName: "pageHeader"
+        title: qsTr("{{app}}")
+    }
+
+    Label {
+        anchors.centerIn: parent
+        text: qsTr("Hello, Aurora OS!")
+    }
+}
+"#;
+
+pub const QML_COVER_PAGE: &str = r#"import QtQuick 2.0
+import Sailfish.Silica 1.0
+
+CoverBackground {
+    objectName: "defaultCover"
+
+    Label {
+        anchors.centerIn: parent
+        text: qsTr("{{app}}")
+    }
+}
+"#;
+
+pub const QML_DESKTOP: &str = r#"[Desktop Entry]
+Type=Application
+Name={{app}}
+Icon={{package}}
+Exec=/usr/bin/{{package}}
+X-Nemo-Application-Type=silica-qt5
+
+[X-Application]
+Permissions=
+OrganizationName={{organization}}
+ApplicationName={{app}}
+"#;
+
+pub const QML_SPEC: &str = r#"Name:       {{package}}
+Summary:    {{app}}
+Version:    0.1.0
+Release:    1
+License:    BSD-3-Clause
+Source0:    %{name}-%{version}.tar.bz2
+
+Requires:   sailfishsilica-qt5 >= 0.10.9
+BuildRequires:  pkgconfig(auroraapp)
+BuildRequires:  pkgconfig(Qt5Core)
+BuildRequires:  pkgconfig(Qt5Qml)
+BuildRequires:  pkgconfig(Qt5Quick)
+BuildRequires:  cmake
+
+%description
+Application {{app}} for Aurora OS.
+
+%prep
+%autosetup
+
+%build
+%cmake
+%make_build
+
+%install
+%make_install
+
+%files
+%defattr(-,root,root,-)
+%{_bindir}/%{name}
+%defattr(644,root,root,-)
+%{_datadir}/%{name}
+%{_datadir}/applications/%{name}.desktop
+%{_datadir}/icons/hicolor/*/apps/%{name}.png
+"#;
+
+/////////////////
+// C++ daemon
+
+pub const CPP_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.5)
+
+project({{package}} CXX)
+
+find_package(Qt5 COMPONENTS Core REQUIRED)
+
+set(CMAKE_AUTOMOC ON)
+set(CMAKE_INCLUDE_CURRENT_DIR ON)
+set(CMAKE_CXX_STANDARD 17)
+set(CMAKE_CXX_STANDARD_REQUIRED ON)
+
+add_executable(${PROJECT_NAME} src/main.cpp)
+
+target_link_libraries(${PROJECT_NAME}
+    Qt5::Core
+)
+
+install(TARGETS ${PROJECT_NAME}
+    RUNTIME DESTINATION bin
+)
+install(FILES ${PROJECT_NAME}.service
+    DESTINATION lib/systemd/user
+)
+"#;
+
+pub const CPP_MAIN_CPP: &str = r#"#include <QCoreApplication>
+#include <QDebug>
+#include <QTimer>
+
+int main(int argc, char* argv[]) {
+    QCoreApplication application(argc, argv);
+    application.setOrganizationName(QStringLiteral("{{organization}}"));
+    application.setApplicationName(QStringLiteral("{{app}}"));
+
+    QTimer timer;
+    QObject::connect(&timer, &QTimer::timeout, []() { qInfo() << "{{app}} is running"; });
+    timer.start(60000);
+
+    return application.exec();
+}
+"#;
+
+pub const CPP_SERVICE: &str = r#"[Unit]
+Description={{app}} daemon
+After=pre-user-session.target
+
+[Service]
+Type=simple
+ExecStart=/usr/bin/{{package}}
+Restart=on-failure
+
+[Install]
+WantedBy=user-session.target
+"#;
+
+pub const CPP_SPEC: &str = r#"Name:       {{package}}
+Summary:    {{app}}
+Version:    0.1.0
+Release:    1
+License:    BSD-3-Clause
+Source0:    %{name}-%{version}.tar.bz2
+
+BuildRequires:  pkgconfig(Qt5Core)
+BuildRequires:  cmake
+
+%description
+Daemon {{app}} for Aurora OS.
+
+%prep
+%autosetup
+
+%build
+%cmake
+%make_build
+
+%install
+%make_install
+
+%files
+%defattr(-,root,root,-)
+%{_bindir}/%{name}
+%defattr(644,root,root,-)
+%{_userunitdir}/%{name}.service
+"#;
+
+/////////////////
+// Flutter application
+
+pub const FLUTTER_PUBSPEC: &str = r#"name: {{app}}
+description: "Flutter application for Aurora OS."
+publish_to: 'none'
+version: 0.1.0
+
+environment:
+  sdk: '>=3.3.0 <4.0.0'
+
+dependencies:
+  flutter:
+    sdk: flutter
+
+dev_dependencies:
+  flutter_test:
+    sdk: flutter
+  flutter_lints: ^3.0.0
+
+flutter:
+  uses-material-design: true
+"#;
+
+pub const FLUTTER_ANALYSIS_OPTIONS: &str = r#"include: package:flutter_lints/flutter.yaml
+"#;
+
+pub const FLUTTER_MAIN_DART: &str = r#"import 'package:flutter/material.dart';
+
+void main() {
+  runApp(const MyApp());
+}
+
+class MyApp extends StatelessWidget {
+  const MyApp({super.key});
+
+  @override
+  Widget build(BuildContext context) {
+    return MaterialApp(
+      title: '{{app}}',
+      home: Scaffold(
+        appBar: AppBar(title: const Text('{{app}}')),
+        body: const Center(child: Text('Hello, Aurora OS!')),
+      ),
+    );
+  }
+}
+"#;
+
+pub const FLUTTER_CMAKE: &str = r#"cmake_minimum_required(VERSION 3.10)
+
+set(BINARY_NAME "{{package}}")
+
+project(runner LANGUAGES CXX)
+
+set(CMAKE_CXX_STANDARD 17)
+set(CMAKE_CXX_STANDARD_REQUIRED ON)
+
+set(CMAKE_CXX_FLAGS "${CMAKE_CXX_FLAGS} -Wall -Wextra")
+set(CMAKE_CXX_FLAGS_RELEASE "-O3")
+set(CMAKE_SKIP_RPATH OFF)
+set(CMAKE_INSTALL_RPATH "\$ORIGIN/../share/${BINARY_NAME}/lib")
+
+set(FLUTTER_DIR ${CMAKE_CURRENT_SOURCE_DIR}/flutter)
+set(ROOT_PROJECT_BINARY_DIR ${PROJECT_BINARY_DIR})
+
+find_package(PkgConfig REQUIRED)
+pkg_check_modules(FlutterEmbedder REQUIRED IMPORTED_TARGET flutter-embedder)
+
+add_executable(${BINARY_NAME} main.cpp ${FLUTTER_DIR}/generated_plugin_registrant.cpp)
+target_link_libraries(${BINARY_NAME} PRIVATE PkgConfig::FlutterEmbedder)
+target_include_directories(${BINARY_NAME} PRIVATE ${FLUTTER_DIR})
+
+include(flutter/generated_plugins.cmake)
+
+set(PACKAGE_INSTALL_DIR ${CMAKE_INSTALL_PREFIX}/share/${BINARY_NAME})
+
+install(TARGETS ${BINARY_NAME} RUNTIME DESTINATION ${CMAKE_INSTALL_PREFIX}/bin)
+install(FILES desktop/${BINARY_NAME}.desktop DESTINATION ${CMAKE_INSTALL_PREFIX}/share/applications)
+
+foreach(_size 86 108 128 172)
+    install(FILES icons/${_size}x${_size}.png
+        DESTINATION ${CMAKE_INSTALL_PREFIX}/share/icons/hicolor/${_size}x${_size}/apps
+        RENAME ${BINARY_NAME}.png
+    )
+endforeach()
+"#;
+
+pub const FLUTTER_MAIN_CPP: &str = r#"#include <flutter/flutter_aurora.h>
+
+#include "generated_plugin_registrant.h"
+
+int main(int argc, char* argv[]) {
+    aurora::Initialize(argc, argv);
+    aurora::RegisterPlugins();
+    aurora::Launch();
+    return 0;
+}
+"#;
+
+pub const FLUTTER_DESKTOP: &str = r#"[Desktop Entry]
+Type=Application
+Name={{app}}
+Icon={{package}}
+Exec=/usr/bin/{{package}}
+X-Nemo-Application-Type=silica-qt5
+
+[X-Application]
+Permissions=
+OrganizationName={{organization}}
+ApplicationName={{app}}
+"#;
+
+pub const FLUTTER_SPEC: &str = r#"%global __provides_exclude_from ^%{_datadir}/%{name}/lib/.*$
+%global __requires_exclude ^lib(dconf|flutter-embedder|maliit-glib|.+_platform_plugin)\\.so.*$
+
+Name:       {{package}}
+Summary:    {{app}}
+Version:    0.1.0
+Release:    1
+License:    BSD-3-Clause
+Source0:    %{name}-%{version}.tar.zst
+
+BuildRequires:  cmake
+BuildRequires:  ninja
+
+%description
+Flutter application {{app}} for Aurora OS.
+
+%prep
+%autosetup
+
+%build
+%cmake -GNinja -DCMAKE_BUILD_TYPE=%{_flutter_build_type} -DPSDK_VERSION=%{_flutter_psdk_version} -DPSDK_MAJOR=%{_flutter_psdk_major}
+%ninja_build
+
+%install
+rm -rf %{buildroot}
+%ninja_install
+
+%files
+%{_bindir}/%{name}
+%{_datadir}/%{name}/*
+%{_datadir}/applications/%{name}.desktop
+%{_datadir}/icons/hicolor/*/apps/%{name}.png
+"#;
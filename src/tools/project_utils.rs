@@ -0,0 +1,152 @@
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use image::Rgba;
+use image::RgbaImage;
+use regex::Regex;
+
+use super::macros::tr;
+use super::project_templates as templates;
+
+/// Available templates new project
+pub const TEMPLATES: [&str; 3] = ["qml", "cpp", "flutter"];
+
+/// Sizes icons application Aurora OS
+pub const ICON_SIZES: [u32; 4] = [86, 108, 128, 172];
+
+/// Package name: ru.<org>.<app>
+pub struct ProjectName {
+    pub package: String,
+    pub organization: String,
+    pub app: String,
+}
+
+impl ProjectName {
+    /// Parse and validate package name by template
+    pub fn parse(name: &str, template: &str) -> Result<ProjectName, Box<dyn std::error::Error>> {
+        let parts: Vec<&str> = name.split('.').collect();
+        if parts.len() != 3 || parts[0] != "ru" {
+            Err(tr!("название должно быть в формате ru.<org>.<app>"))?
+        }
+        let re_name = Regex::new(r"^[a-zA-Z][a-zA-Z0-9_]*$")?;
+        if !re_name.is_match(parts[1]) {
+            Err(tr!(
+                "организация должна начинаться с латинской буквы и содержать только латинские буквы, цифры и _"
+            ))?
+        }
+        let re_app = if template == "flutter" {
+            // Dart package name
+            Regex::new(r"^[a-z][a-z0-9_]*$")?
+        } else {
+            re_name
+        };
+        if !re_app.is_match(parts[2]) {
+            if template == "flutter" {
+                Err(tr!(
+                    "название приложения Flutter должно начинаться с буквы и содержать только a-z, 0-9 и _"
+                ))?
+            }
+            Err(tr!(
+                "название приложения должно начинаться с латинской буквы и содержать только латинские буквы, цифры и _"
+            ))?
+        }
+        Ok(ProjectName {
+            package: name.to_string(),
+            organization: format!("{}.{}", parts[0], parts[1]),
+            app: parts[2].to_string(),
+        })
+    }
+
+    /// Fill placeholders template
+    fn fill(&self, content: &str) -> String {
+        content
+            .replace("{{package}}", &self.package)
+            .replace("{{organization}}", &self.organization)
+            .replace("{{app}}", &self.app)
+    }
+}
+
+/// Create project by template in dir {path}/{app}
+pub fn gen_project(path: &Path, template: &str, name: &ProjectName) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let files = match template {
+        "qml" => vec![
+            ("CMakeLists.txt".to_string(), templates::QML_CMAKE),
+            ("src/main.cpp".to_string(), templates::QML_MAIN_CPP),
+            (format!("qml/{}.qml", name.app), templates::QML_APP),
+            ("qml/pages/MainPage.qml".to_string(), templates::QML_MAIN_PAGE),
+            ("qml/cover/DefaultCoverPage.qml".to_string(), templates::QML_COVER_PAGE),
+            (format!("{}.desktop", name.package), templates::QML_DESKTOP),
+            (format!("rpm/{}.spec", name.package), templates::QML_SPEC),
+            (".gitignore".to_string(), templates::GITIGNORE_CPP),
+        ],
+        "cpp" => vec![
+            ("CMakeLists.txt".to_string(), templates::CPP_CMAKE),
+            ("src/main.cpp".to_string(), templates::CPP_MAIN_CPP),
+            (format!("{}.service", name.package), templates::CPP_SERVICE),
+            (format!("rpm/{}.spec", name.package), templates::CPP_SPEC),
+            (".gitignore".to_string(), templates::GITIGNORE_CPP),
+        ],
+        "flutter" => vec![
+            ("pubspec.yaml".to_string(), templates::FLUTTER_PUBSPEC),
+            ("analysis_options.yaml".to_string(), templates::FLUTTER_ANALYSIS_OPTIONS),
+            ("lib/main.dart".to_string(), templates::FLUTTER_MAIN_DART),
+            ("aurora/CMakeLists.txt".to_string(), templates::FLUTTER_CMAKE),
+            ("aurora/main.cpp".to_string(), templates::FLUTTER_MAIN_CPP),
+            (
+                format!("aurora/desktop/{}.desktop", name.package),
+                templates::FLUTTER_DESKTOP,
+            ),
+            (format!("aurora/rpm/{}.spec", name.package), templates::FLUTTER_SPEC),
+            (".gitignore".to_string(), templates::GITIGNORE_FLUTTER),
+        ],
+        _ => Err(tr!("неизвестный шаблон: {}", template))?,
+    };
+    let project = path.join(&name.app);
+    if project.exists() && fs::read_dir(&project)?.next().is_some() {
+        Err(tr!("директория {} уже существует", project.to_string_lossy()))?
+    }
+    // Files
+    for (file, content) in files {
+        write_file(&project.join(file), &name.fill(content))?;
+    }
+    // Style for cpp_format
+    write_file(&project.join(".clang-format"), templates::CLANG_FORMAT)?;
+    // Icons
+    for size in ICON_SIZES {
+        let icon = match template {
+            "qml" => project.join(format!("icons/{size}x{size}/{}.png", name.package)),
+            "flutter" => project.join(format!("aurora/icons/{size}x{size}.png")),
+            _ => continue,
+        };
+        gen_icon(&icon, size)?;
+    }
+    Ok(project)
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+/// Placeholder icon: circle on transparent background
+fn gen_icon(path: &Path, size: u32) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let radius = size as f32 / 2.0;
+    let image = RgbaImage::from_fn(size, size, |x, y| {
+        let dx = x as f32 + 0.5 - radius;
+        let dy = y as f32 + 0.5 - radius;
+        if (dx * dx + dy * dy).sqrt() <= radius {
+            Rgba([0x1e, 0x88, 0xe5, 0xff])
+        } else {
+            Rgba([0, 0, 0, 0])
+        }
+    });
+    image.save(path)?;
+    Ok(())
+}
@@ -0,0 +1,319 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+
+use rpm::CompressionType;
+use rpm::Dependency;
+use rpm::DependencyFlags;
+use rpm::FileMode;
+use rpm::IndexSignatureTag;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::macros::tr;
+
+/// File from payload RPM, path absolute in system
+pub struct RpmFile {
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+/// Read files from payload RPM by filter path
+pub fn read_files<T: Fn(&str) -> bool>(
+    package: &rpm::Package,
+    filter: T,
+) -> Result<Vec<RpmFile>, Box<dyn std::error::Error>> {
+    let content = package.content.as_slice();
+    let mut reader: Box<dyn Read> = match package.metadata.get_payload_compressor()? {
+        CompressionType::None => Box::new(content),
+        CompressionType::Gzip => Box::new(flate2::read::GzDecoder::new(content)),
+        CompressionType::Zstd => Box::new(zstd::stream::read::Decoder::new(content)?),
+        CompressionType::Xz => Box::new(xz2::read::XzDecoder::new(content)),
+        CompressionType::Bzip2 => Err(tr!("сжатие bzip2 не поддерживается"))?,
+    };
+    let mut files: Vec<RpmFile> = vec![];
+    loop {
+        let mut entry = cpio::NewcReader::new(reader)?;
+        if entry.entry().is_trailer() {
+            break;
+        }
+        // ./usr/share/applications/name.desktop
+        let path = format!(
+            "/{}",
+            entry.entry().name().trim_start_matches("./").trim_start_matches('/')
+        );
+        if filter(&path) {
+            let mut data: Vec<u8> = vec![];
+            entry.read_to_end(&mut data)?;
+            files.push(RpmFile { path, data });
+        }
+        reader = entry.finish()?;
+    }
+    Ok(files)
+}
+
+/// Open RPM package
+pub fn open(path: &Path) -> Result<rpm::Package, Box<dyn std::error::Error>> {
+    match rpm::Package::open(path) {
+        Ok(value) => Ok(value),
+        Err(_) => Err(tr!("не удалось прочитать пакет: {}", path.to_string_lossy()))?,
+    }
+}
+
+/// Metadata RPM package
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RpmInfo {
+    pub name: String,
+    pub version: String,
+    pub release: String,
+    pub arch: String,
+    pub summary: String,
+    pub license: String,
+    pub size: u64,
+    pub is_signed: bool,
+    pub files: Vec<RpmFileInfo>,
+    pub requires: Vec<String>,
+    pub provides: Vec<String>,
+    pub scriptlets: Vec<RpmScriptlet>,
+}
+
+impl RpmInfo {
+    /// name-version-release.arch
+    pub fn full_name(&self) -> String {
+        format!("{}-{}-{}.{}", self.name, self.version, self.release, self.arch)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct RpmFileInfo {
+    pub path: String,
+    pub mode: String,
+    pub user: String,
+    pub group: String,
+    pub size: usize,
+    pub digest: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct RpmScriptlet {
+    pub name: String,
+    pub program: Option<String>,
+    pub script: String,
+}
+
+/// Changed value: field, file or scriptlet
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RpmDiffChange {
+    pub name: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// Difference between two RPM packages
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RpmDiff {
+    pub old: String,
+    pub new: String,
+    pub fields: Vec<RpmDiffChange>,
+    pub files_added: Vec<String>,
+    pub files_removed: Vec<String>,
+    pub files_changed: Vec<RpmDiffChange>,
+    pub requires_added: Vec<String>,
+    pub requires_removed: Vec<String>,
+    pub provides_added: Vec<String>,
+    pub provides_removed: Vec<String>,
+    pub scriptlets_changed: Vec<RpmDiffChange>,
+}
+
+impl RpmDiff {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+            && self.files_added.is_empty()
+            && self.files_removed.is_empty()
+            && self.files_changed.is_empty()
+            && self.requires_added.is_empty()
+            && self.requires_removed.is_empty()
+            && self.provides_added.is_empty()
+            && self.provides_removed.is_empty()
+            && self.scriptlets_changed.is_empty()
+    }
+}
+
+/// Read metadata RPM package
+pub fn get_info(path: &Path) -> Result<RpmInfo, Box<dyn std::error::Error>> {
+    let package = open(path)?;
+    let metadata = &package.metadata;
+    let files = metadata
+        .get_file_entries()?
+        .into_iter()
+        .map(|e| RpmFileInfo {
+            path: e.path.to_string_lossy().to_string(),
+            mode: mode_to_string(&e.mode),
+            user: e.ownership.user,
+            group: e.ownership.group,
+            size: e.size,
+            digest: e.digest.map(|e| e.as_hex().to_string()),
+        })
+        .collect();
+    let scriptlets = [
+        ("%pretrans", metadata.get_pre_trans_script()),
+        ("%pre", metadata.get_pre_install_script()),
+        ("%post", metadata.get_post_install_script()),
+        ("%preun", metadata.get_pre_uninstall_script()),
+        ("%postun", metadata.get_post_uninstall_script()),
+        ("%posttrans", metadata.get_post_trans_script()),
+    ]
+    .into_iter()
+    .filter_map(|(name, scriptlet)| {
+        scriptlet.ok().map(|e| RpmScriptlet {
+            name: name.to_string(),
+            program: e.program.map(|e| e.join(" ")),
+            script: e.script,
+        })
+    })
+    .collect();
+    Ok(RpmInfo {
+        name: metadata.get_name()?.to_string(),
+        version: metadata.get_version()?.to_string(),
+        release: metadata.get_release()?.to_string(),
+        arch: metadata.get_arch()?.to_string(),
+        summary: metadata.get_summary().unwrap_or_default().to_string(),
+        license: metadata.get_license().unwrap_or_default().to_string(),
+        size: metadata.get_installed_size().unwrap_or_default(),
+        is_signed: [
+            IndexSignatureTag::RPMSIGTAG_RSA,
+            IndexSignatureTag::RPMSIGTAG_DSA,
+            IndexSignatureTag::RPMSIGTAG_PGP,
+            IndexSignatureTag::RPMSIGTAG_GPG,
+        ]
+        .into_iter()
+        .any(|e| metadata.signature.entry_is_present(e)),
+        files,
+        requires: metadata.get_requires()?.iter().map(dependency_to_string).collect(),
+        provides: metadata.get_provides()?.iter().map(dependency_to_string).collect(),
+        scriptlets,
+    })
+}
+
+/// Compare two RPM packages
+pub fn diff_info(old: &RpmInfo, new: &RpmInfo) -> RpmDiff {
+    let fields = [
+        ("Name", &old.name, &new.name),
+        ("Version", &old.version, &new.version),
+        ("Release", &old.release, &new.release),
+        ("Arch", &old.arch, &new.arch),
+        ("Summary", &old.summary, &new.summary),
+        ("License", &old.license, &new.license),
+        ("Size", &old.size.to_string(), &new.size.to_string()),
+        ("Signed", &old.is_signed.to_string(), &new.is_signed.to_string()),
+    ]
+    .into_iter()
+    .filter(|(_, old, new)| old != new)
+    .map(|(name, old, new)| RpmDiffChange {
+        name: name.to_string(),
+        old: old.clone(),
+        new: new.clone(),
+    })
+    .collect();
+    // Files
+    let old_files: HashMap<&String, &RpmFileInfo> = old.files.iter().map(|e| (&e.path, e)).collect();
+    let new_files: HashMap<&String, &RpmFileInfo> = new.files.iter().map(|e| (&e.path, e)).collect();
+    // Content compare by digest, size can be same
+    let file_to_string = |e: &RpmFileInfo| {
+        let digest = e.digest.as_deref().map(|e| &e[..e.len().min(12)]).unwrap_or("-");
+        format!("{} {}:{} {} {}", e.mode, e.user, e.group, e.size, digest)
+    };
+    let files_changed = new
+        .files
+        .iter()
+        .filter_map(|e| {
+            old_files
+                .get(&e.path)
+                .filter(|old| **old != e)
+                .map(|old| RpmDiffChange {
+                    name: e.path.clone(),
+                    old: file_to_string(old),
+                    new: file_to_string(e),
+                })
+        })
+        .collect();
+    // Scriptlets
+    let mut scriptlets_changed: Vec<RpmDiffChange> = vec![];
+    for name in ["%pretrans", "%pre", "%post", "%preun", "%postun", "%posttrans"] {
+        let old = old.scriptlets.iter().find(|e| e.name == name);
+        let new = new.scriptlets.iter().find(|e| e.name == name);
+        if old != new {
+            scriptlets_changed.push(RpmDiffChange {
+                name: name.to_string(),
+                old: old.map(|e| e.script.clone()).unwrap_or_default(),
+                new: new.map(|e| e.script.clone()).unwrap_or_default(),
+            });
+        }
+    }
+    RpmDiff {
+        old: old.full_name(),
+        new: new.full_name(),
+        fields,
+        files_added: new
+            .files
+            .iter()
+            .filter(|e| !old_files.contains_key(&e.path))
+            .map(|e| e.path.clone())
+            .collect(),
+        files_removed: old
+            .files
+            .iter()
+            .filter(|e| !new_files.contains_key(&e.path))
+            .map(|e| e.path.clone())
+            .collect(),
+        files_changed,
+        requires_added: diff_list(&new.requires, &old.requires),
+        requires_removed: diff_list(&old.requires, &new.requires),
+        provides_added: diff_list(&new.provides, &old.provides),
+        provides_removed: diff_list(&old.provides, &new.provides),
+        scriptlets_changed,
+    }
+}
+
+/// Values from first not found in second
+fn diff_list(first: &[String], second: &[String]) -> Vec<String> {
+    first.iter().filter(|e| !second.contains(e)).cloned().collect()
+}
+
+/// name >= version
+fn dependency_to_string(dependency: &Dependency) -> String {
+    let flags = dependency.flags;
+    let operator = if flags.contains(DependencyFlags::GE) {
+        ">="
+    } else if flags.contains(DependencyFlags::LE) {
+        "<="
+    } else if flags.contains(DependencyFlags::EQUAL) {
+        "="
+    } else if flags.contains(DependencyFlags::GREATER) {
+        ">"
+    } else if flags.contains(DependencyFlags::LESS) {
+        "<"
+    } else {
+        return dependency.name.clone();
+    };
+    format!("{} {} {}", dependency.name, operator, dependency.version)
+}
+
+/// Mode as ls: -rwxr-xr-x
+fn mode_to_string(mode: &FileMode) -> String {
+    let kind = match mode {
+        FileMode::Dir { .. } => 'd',
+        FileMode::SymbolicLink { .. } => 'l',
+        _ => '-',
+    };
+    let permissions = mode.permissions();
+    let mut result = String::from(kind);
+    for (index, char) in ['r', 'w', 'x', 'r', 'w', 'x', 'r', 'w', 'x'].iter().enumerate() {
+        if permissions & (1 << (8 - index)) != 0 {
+            result.push(*char);
+        } else {
+            result.push('-');
+        }
+    }
+    result
+}
@@ -80,8 +80,100 @@ pub fn config_get_bool(params: &Vec<String>, key: &str, find: &str) -> Result<bo
     }
 }
 
-pub fn search_files_by_home(key: &str) -> Vec<String> {
-    search_files_by_path(key, &get_home_folder_path())
+/// Directories without toolchains, skip when search
+const SEARCH_SKIP_DIRS: [&str; 13] = [
+    "node_modules",
+    ".git",
+    ".svn",
+    ".cache",
+    ".pub-cache",
+    ".dart_tool",
+    ".gradle",
+    ".m2",
+    ".npm",
+    ".cargo",
+    ".rustup",
+    ".thumbnails",
+    "Trash",
+];
+
+/// Max depth search toolchains from root
+const SEARCH_MAX_DEPTH: usize = 7;
+
+/// Search file in known dirs (absolute or relative home) and in home with limit depth
+pub fn search_files_by_home(search: &str, known: &[&str]) -> Vec<String> {
+    let home = get_home_folder_path();
+    let known: Vec<PathBuf> = known.iter().map(|e| home.join(e)).filter(|e| e.is_dir()).collect();
+    let mut result: Vec<String> = vec![];
+    for path in known.iter().flat_map(|e| _search_files_by_root(search, e, &[])) {
+        if !result.contains(&path) {
+            result.push(path);
+        }
+    }
+    // Split home by top dirs for parallel walk, known dirs already checked
+    let mut roots: Vec<PathBuf> = vec![];
+    if let Ok(entries) = fs::read_dir(&home) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir()
+                && !_is_search_skip(&entry.file_name().to_string_lossy())
+                && !known.contains(&entry.path())
+            {
+                roots.push(entry.path());
+            }
+        }
+    }
+    let threads = std::thread::available_parallelism().map(|e| e.get()).unwrap_or(4);
+    let mut chunks: Vec<Vec<PathBuf>> = vec![vec![]; threads];
+    for (index, root) in roots.into_iter().enumerate() {
+        chunks[index % threads].push(root);
+    }
+    let found = std::thread::scope(|scope| {
+        let handles = chunks
+            .iter()
+            .map(|chunk| {
+                let known = &known;
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .flat_map(|root| _search_files_by_root(search, root, known))
+                        .collect::<Vec<String>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .filter_map(|e| e.join().ok())
+            .flatten()
+            .collect::<Vec<String>>()
+    });
+    for path in found {
+        if !result.contains(&path) {
+            result.push(path);
+        }
+    }
+    result
+}
+
+fn _is_search_skip(name: &str) -> bool {
+    SEARCH_SKIP_DIRS.contains(&name)
+}
+
+fn _search_files_by_root(search: &str, root: &Path, skip: &[PathBuf]) -> Vec<String> {
+    WalkDir::new(root)
+        .max_depth(SEARCH_MAX_DEPTH)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| {
+            !(e.file_type().is_dir()
+                && (_is_search_skip(&e.file_name().to_string_lossy()) || skip.iter().any(|s| s == e.path())))
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().ends_with(search) && is_file(e))
+        .map(|e| e.path().to_string_lossy().to_string())
+        .collect()
 }
 
 /// Search file by PC
@@ -118,6 +210,11 @@ pub fn parse_output(out: Vec<u8>) -> Vec<String> {
     vec![]
 }
 
+/// Quote argument for remote shell: 'value'
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
 /// Get incoming object from query
 pub fn clear_to_model_body(value: &String) -> Result<String, Box<dyn std::error::Error>> {
     if value.contains("jsonData") {
@@ -228,13 +325,13 @@ pub fn get_screenshot_save_path() -> PathBuf {
 }
 
 /// Gen path for screenshot
-pub fn get_report_save_path() -> PathBuf {
+pub fn get_report_save_path(extension: &str) -> PathBuf {
     let start = SystemTime::now();
     let timestamp = start
         .duration_since(UNIX_EPOCH)
         .expect("Time went backwards")
         .as_millis();
-    let file_name = format!("Report_{}.pdf", timestamp.to_string());
+    let file_name = format!("Report_{}.{}", timestamp.to_string(), extension);
     let en_path = get_home_folder_path().join("Documents");
     if en_path.exists() {
         en_path.join(file_name)
@@ -348,6 +445,12 @@ pub fn get_repo_flutter() -> Vec<GitlabTagsResponse> {
     ClientRequest::new(None).get_repo_tags_flutter()
 }
 
+/// Get list Aurora plugins Flutter
+#[once(time = 300)]
+pub fn get_repo_flutter_plugins() -> Vec<GitlabTagsResponse> {
+    ClientRequest::new(None).get_repo_tags_flutter_plugins()
+}
+
 /// Get list demo app from repo
 #[once(time = 300)]
 pub fn get_demo_apps() -> Vec<DemoReleasesResponse> {
@@ -407,7 +510,79 @@ pub fn add_file_sudoers(file_name: String, file_content: String) -> Result<(), B
     let path_move = format!("/etc/sudoers.d/{}", file_name);
     // Change owned
     let _ = exec::exec_wait_args(&sudo, ["chown", "root:root", &path_create])?;
+    let _ = exec::exec_wait_args(&sudo, ["chmod", "0440", &path_create])?;
+    // Validate, broken sudoers block sudo
+    let output = exec::exec_wait_args(&sudo, ["visudo", "-c", "-f", &path_create])?;
+    if !output.status.success() {
+        let _ = exec::exec_wait_args(&sudo, ["rm", "-f", &path_create]);
+        Err(tr!("запись sudoers не прошла проверку visudo"))?
+    }
     // Move to sudoers
     let _ = exec::exec_wait_args(&sudo, ["mv", &path_create, &path_move])?;
     Ok(())
 }
+
+/// Read record from sudoers, empty if not exist
+pub fn read_file_sudoers(file_name: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let sudo = programs::get_sudo()?;
+    let path = format!("/etc/sudoers.d/{}", file_name);
+    // Dir sudoers.d not readable by user, check by exit status
+    if !exec::exec_wait_args(&sudo, ["test", "-e", &path])?.status.success() {
+        return Ok(vec![]);
+    }
+    let output = exec::exec_wait_args(&sudo, ["cat", &path])?;
+    if output.status.success() {
+        Ok(parse_output(output.stdout))
+    } else {
+        Err(tr!("не удалось прочитать запись sudoers"))?
+    }
+}
+
+/// Remove record from sudoers
+pub fn remove_file_sudoers(file_name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let sudo = programs::get_sudo()?;
+    let path = format!("/etc/sudoers.d/{}", file_name);
+    let output = exec::exec_wait_args(&sudo, ["rm", "-f", &path])?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(tr!("не удалось удалить запись sudoers"))?
+    }
+}
+
+/// Search pids process by path to executable through /proc
+pub fn get_pids_by_exe(program: &str) -> Vec<u32> {
+    let program = fs::canonicalize(program).unwrap_or_else(|_| PathBuf::from(program));
+    let entries = match fs::read_dir("/proc") {
+        Ok(value) => value,
+        Err(_) => return vec![],
+    };
+    let mut pids: Vec<u32> = vec![];
+    for entry in entries.filter_map(|e| e.ok()) {
+        let pid = match entry.file_name().to_string_lossy().parse::<u32>() {
+            Ok(value) => value,
+            Err(_) => continue,
+        };
+        let exe = match fs::read_link(entry.path().join("exe")) {
+            Ok(value) => value,
+            // Fallback: first argument cmdline
+            Err(_) => match fs::read(entry.path().join("cmdline")) {
+                Ok(value) => PathBuf::from(String::from_utf8_lossy(&value).split('\0').next().unwrap_or_default()),
+                Err(_) => continue,
+            },
+        };
+        if exe == program {
+            pids.push(pid);
+        }
+    }
+    pids
+}
+
+/// Check process is alive (not exist or zombie)
+pub fn is_pid_running(pid: u32) -> bool {
+    match fs::read_to_string(format!("/proc/{pid}/stat")) {
+        // pid (comm) state ...
+        Ok(stat) => stat.rsplit(')').next().and_then(|e| e.split_whitespace().next()) != Some("Z"),
+        Err(_) => false,
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::lint_utils;
+use super::macros::tr;
+use super::project_utils::ICON_SIZES;
+use super::rpm_utils;
+
+/// Permissions Aurora OS for key Permissions in section X-Application
+const DESKTOP_PERMISSIONS: [&str; 22] = [
+    "Audio",
+    "Background",
+    "Bluetooth",
+    "Camera",
+    "Compatibility",
+    "DeviceInfo",
+    "Documents",
+    "Downloads",
+    "Internet",
+    "Location",
+    "MediaIndexing",
+    "Microphone",
+    "Music",
+    "NFC",
+    "PictureCache",
+    "Pictures",
+    "PushNotifications",
+    "RemovableMedia",
+    "SecureStorage",
+    "Sensors",
+    "UserDirs",
+    "Videos",
+];
+
+/// Keys required in section Desktop Entry
+const DESKTOP_REQUIRED_KEYS: [&str; 5] = ["Type", "Name", "Icon", "Exec", "X-Nemo-Application-Type"];
+
+/// Result check, file relative project or absolute in RPM
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ValidateCheck {
+    pub file: String,
+    pub passed: bool,
+    #[serde(default)]
+    pub warning: bool,
+    pub message: String,
+}
+
+impl ValidateCheck {
+    fn new(file: &str, passed: bool, message: String) -> ValidateCheck {
+        ValidateCheck {
+            file: file.to_string(),
+            passed,
+            warning: false,
+            message,
+        }
+    }
+
+    /// Check passed with warning, not fail validate
+    fn new_warning(file: &str, message: String) -> ValidateCheck {
+        ValidateCheck {
+            file: file.to_string(),
+            passed: true,
+            warning: true,
+            message,
+        }
+    }
+}
+
+/// Application in project or package: desktop and icons by size
+struct ValidateApp {
+    name: String,
+    desktop: (String, String),
+    icons: HashMap<u32, (String, Vec<u8>)>,
+}
+
+/// Validate desktop and icons project dir or RPM package
+pub fn validate(path: &Path) -> Result<Vec<ValidateCheck>, Box<dyn std::error::Error>> {
+    let apps = if path.is_dir() {
+        get_apps_project(path)?
+    } else if path.extension().is_some_and(|e| e == "rpm") {
+        get_apps_package(path)?
+    } else {
+        Err(tr!("укажите директорию проекта или пакет RPM"))?
+    };
+    if apps.is_empty() {
+        return Ok(vec![ValidateCheck::new(
+            &path.to_string_lossy(),
+            false,
+            tr!("не найден файл .desktop"),
+        )]);
+    }
+    let mut checks: Vec<ValidateCheck> = vec![];
+    for app in apps {
+        checks.append(&mut validate_desktop(&app.desktop.0, &app.desktop.1, &app.name));
+        for size in ICON_SIZES {
+            checks.push(validate_icon(app.icons.get(&size), size, &app.name));
+        }
+    }
+    Ok(checks)
+}
+
+fn get_apps_project(path: &Path) -> Result<Vec<ValidateApp>, Box<dyn std::error::Error>> {
+    let files = lint_utils::search_project_files(path);
+    let mut apps: Vec<ValidateApp> = vec![];
+    for desktop in files.iter().filter(|e| e.extension().is_some_and(|e| e == "desktop")) {
+        let name = desktop.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        let mut icons: HashMap<u32, (String, Vec<u8>)> = HashMap::new();
+        for size in ICON_SIZES {
+            let dir = format!("{size}x{size}");
+            // icons/86x86/name.png or aurora/icons/86x86.png
+            let mut candidates = files
+                .iter()
+                .map(|e| (e, lint_utils::relative(path, e)))
+                .filter(|(e, relative)| {
+                    e.extension().is_some_and(|e| e == "png")
+                        && relative.split('/').any(|e| e == dir || e == format!("{dir}.png"))
+                })
+                .collect::<Vec<_>>();
+            candidates.sort_by_key(|(_, relative)| !relative.contains(&name));
+            if let Some((icon, relative)) = candidates.first() {
+                icons.insert(size, (relative.clone(), fs::read(icon)?));
+            }
+        }
+        apps.push(ValidateApp {
+            desktop: (lint_utils::relative(path, desktop), fs::read_to_string(desktop)?),
+            name,
+            icons,
+        });
+    }
+    Ok(apps)
+}
+
+fn get_apps_package(path: &Path) -> Result<Vec<ValidateApp>, Box<dyn std::error::Error>> {
+    let package = rpm_utils::open(path)?;
+    let name = package.metadata.get_name()?.to_string();
+    let desktop_path = format!("/usr/share/applications/{}.desktop", name);
+    let icon_path = |size: u32| format!("/usr/share/icons/hicolor/{size}x{size}/apps/{}.png", name);
+    let files = rpm_utils::read_files(&package, |e| {
+        e == desktop_path || ICON_SIZES.iter().any(|size| e == icon_path(*size))
+    })?;
+    let Some(desktop) = files.iter().find(|e| e.path == desktop_path) else {
+        return Ok(vec![]);
+    };
+    let mut icons: HashMap<u32, (String, Vec<u8>)> = HashMap::new();
+    for size in ICON_SIZES {
+        if let Some(icon) = files.iter().find(|e| e.path == icon_path(size)) {
+            icons.insert(size, (icon.path.clone(), icon.data.clone()));
+        }
+    }
+    Ok(vec![ValidateApp {
+        desktop: (desktop.path.clone(), String::from_utf8_lossy(&desktop.data).to_string()),
+        name,
+        icons,
+    }])
+}
+
+fn validate_desktop(file: &str, content: &str, name: &str) -> Vec<ValidateCheck> {
+    // Parse sections
+    let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut section = String::new();
+    for line in content.lines().map(|e| e.trim()) {
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line.trim_matches(['[', ']']).to_string();
+            sections.entry(section.clone()).or_default();
+        } else if let (Some((key, value)), Some(values)) = (line.split_once('='), sections.get_mut(&section)) {
+            values.insert(key.trim().to_string(), value.trim().to_string());
+        }
+    }
+    let mut checks: Vec<ValidateCheck> = vec![];
+    // Desktop Entry
+    match sections.get("Desktop Entry") {
+        Some(entry) => {
+            for key in DESKTOP_REQUIRED_KEYS {
+                let passed = entry.get(key).is_some_and(|e| !e.is_empty());
+                checks.push(ValidateCheck::new(file, passed, tr!("ключ {} задан", key)));
+            }
+            checks.push(ValidateCheck::new(
+                file,
+                entry.get("Type").is_some_and(|e| e == "Application"),
+                tr!("Type=Application"),
+            ));
+            checks.push(ValidateCheck::new(
+                file,
+                entry.get("Icon").is_some_and(|e| e == name),
+                tr!("Icon совпадает с названием пакета {}", name),
+            ));
+            checks.push(ValidateCheck::new(
+                file,
+                entry
+                    .get("Exec")
+                    .and_then(|e| e.split_whitespace().next())
+                    .is_some_and(|e| e.ends_with(&format!("/{}", name)) || e == name),
+                tr!("Exec запускает {}", name),
+            ));
+        }
+        None => checks.push(ValidateCheck::new(file, false, tr!("секция [Desktop Entry] задана"))),
+    }
+    // X-Application
+    match sections.get("X-Application") {
+        Some(application) => {
+            let organization = application.get("OrganizationName").filter(|e| !e.is_empty());
+            let app = application.get("ApplicationName").filter(|e| !e.is_empty());
+            checks.push(ValidateCheck::new(
+                file,
+                organization.is_some(),
+                tr!("ключ OrganizationName задан"),
+            ));
+            checks.push(ValidateCheck::new(
+                file,
+                app.is_some(),
+                tr!("ключ ApplicationName задан"),
+            ));
+            if let (Some(organization), Some(app)) = (organization, app) {
+                checks.push(ValidateCheck::new(
+                    file,
+                    format!("{}.{}", organization, app) == name,
+                    tr!("OrganizationName.ApplicationName совпадает с {}", name),
+                ));
+            }
+            match application.get("Permissions") {
+                Some(permissions) => {
+                    let unknown = permissions
+                        .split(';')
+                        .map(|e| e.trim())
+                        .filter(|e| !e.is_empty() && !DESKTOP_PERMISSIONS.contains(e))
+                        .collect::<Vec<&str>>();
+                    if unknown.is_empty() {
+                        checks.push(ValidateCheck::new(file, true, tr!("разрешения Permissions корректны")));
+                    } else {
+                        checks.push(ValidateCheck::new_warning(
+                            file,
+                            tr!("неизвестные разрешения Permissions: {}", unknown.join(", ")),
+                        ));
+                    }
+                }
+                None => checks.push(ValidateCheck::new(file, false, tr!("ключ Permissions задан"))),
+            }
+        }
+        None => checks.push(ValidateCheck::new(file, false, tr!("секция [X-Application] задана"))),
+    }
+    checks
+}
+
+fn validate_icon(icon: Option<&(String, Vec<u8>)>, size: u32, name: &str) -> ValidateCheck {
+    let Some((file, data)) = icon else {
+        return ValidateCheck::new(name, false, tr!("иконка {}x{} найдена", size, size));
+    };
+    match image::load_from_memory(data) {
+        Ok(image) if image.width() == size && image.height() == size => {
+            ValidateCheck::new(file, true, tr!("иконка {}x{}", size, size))
+        }
+        Ok(image) => ValidateCheck::new(
+            file,
+            false,
+            tr!(
+                "иконка {}x{} имеет размер {}x{}",
+                size,
+                size,
+                image.width(),
+                image.height()
+            ),
+        ),
+        Err(_) => ValidateCheck::new(file, false, tr!("иконка {}x{} не является изображением", size, size)),
+    }
+}